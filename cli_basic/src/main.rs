@@ -1,50 +1,31 @@
 use rusty_poker_core::{
-  game::{Game, Phase},
+  game::Game,
   player::{BasicPlayer, CallingPlayer, Player},
+  runner::GameRunner,
 };
 
 mod terminal_player;
 
 use terminal_player::*;
 
-fn iterate_with_player(game: &mut Game, players: &[&mut dyn Player]) -> Option<Phase> {
-  if let Some(curr_index) = game.get_current_player_index() {
-    let action = players[curr_index as usize].request_action(game.get_state(Some(curr_index)));
-    game.action_current_player(action).unwrap();
-  }
-  game.next()
-}
-
 fn main() {
   println!("Testing a game!");
 
-  let mut calling_players = vec![
-    CallingPlayer { id: 1 },
-    CallingPlayer { id: 2 },
-    CallingPlayer { id: 3 },
-  ];
-
-  let mut _com_player = BasicPlayer { id: 1 };
-  let mut terminal_player = TerminalPlayer {};
-
-  let players: Vec<&mut dyn Player> = vec![&mut calling_players[0], &mut terminal_player];
-
-  let mut game = Game::create(2, 200);
-
-  let mut rounds_played = 1;
-
-  println!(
-    "==== Round {} ============================================================================================",
-    rounds_played
-  );
-  while let Some(phase) = iterate_with_player(&mut game, &players) {
-    if phase == Phase::Init {
-      rounds_played += 1;
-      println!(
-        "==== Round {} ============================================================================================",
-        rounds_played
-      );
-    }
+  let _com_player = BasicPlayer { id: 1 };
+
+  let players: Vec<Box<dyn Player>> = vec![Box::new(CallingPlayer { id: 1 }), Box::new(TerminalPlayer {})];
+
+  let mut runner = GameRunner::create(Game::create(2, 200), players);
+
+  let mut rounds_played = 0;
+
+  while !runner.is_finished() {
+    rounds_played += 1;
+    println!(
+      "==== Round {} ============================================================================================",
+      rounds_played
+    );
+    runner.run_hands(1);
   }
 
   // println!("Player COM{} ends up with ${}", calling_players[0].id, calling_players[0].wallet);
@@ -1,15 +1,14 @@
 use rusty_poker_core::{
-  evaluator::{chance_to_win, chance_to_win_preflop},
   game::{BettingAction, GameState, Phase},
-  player::Player,
+  player::{PendingAction, Player},
 };
 use text_io::try_read;
 
 pub struct TerminalPlayer {}
 
 impl Player for TerminalPlayer {
-  fn request_action(&self, info: GameState) -> BettingAction {
-    let num_players = info.players.len() as u8;
+  fn request_action(&mut self, info: GameState) -> PendingAction {
+    let num_players = info.get_num_players_in_hand();
 
     println!(
       "Your turn:  WALLET: ${}    POT: ${}   CALL: ${}   HAND: {}   TABLE: {}   EST: {:.2}%",
@@ -19,13 +18,13 @@ impl Player for TerminalPlayer {
       info.hand,
       info.table,
       if info.phase > Phase::PreFlop {
-        chance_to_win(&info.table, &info.hand) * 100.00
+        info.variant.chance_to_win(&info.table, &info.hand) * 100.00
       } else {
-        chance_to_win_preflop(&info.hand, num_players)
+        info.variant.chance_to_win_preflop(&info.hand, num_players)
       }
     );
 
-    loop {
+    let action = loop {
       let bet_input: Result<u32, _> = try_read!("{}");
       if bet_input.is_err() {
         continue;
@@ -45,6 +44,7 @@ impl Player for TerminalPlayer {
       } else {
         break BettingAction::Call;
       }
-    }
+    };
+    PendingAction::ready(action)
   }
 }
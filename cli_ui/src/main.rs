@@ -1,7 +1,9 @@
 mod ui;
 
+use std::env;
 use ui::run_tui;
 
 fn main() {
-  run_tui().unwrap();
+  // Connects to a server at the given address, otherwise plays a local game against computer players.
+  run_tui(env::args().nth(1)).unwrap();
 }
@@ -6,29 +6,35 @@ use crossterm::{
   terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use rusty_poker_core::{
-  game::{Game, GameState},
+  game::{Game, GameState, TimeLimit, Viewer},
   player::{CallingPlayer, Player},
+  runner::GameRunner,
 };
 use tui::{
   backend::{Backend, CrosstermBackend},
   layout::Rect,
-  layout::{Constraint, Direction, Layout},
+  layout::{Alignment, Constraint, Direction, Layout},
+  widgets::Paragraph,
   Frame, Terminal,
 };
 
 mod actions;
+mod remote_table;
 mod render;
 
 use actions::ActionsState;
+use remote_table::RemoteTable;
 use render::{draw_player_info, draw_table};
 
-fn render<B: Backend>(f: &mut Frame<B>, game_state: &GameState, actions_state: &mut ActionsState) {
+const EXIT_PROMPT: &str = "Press any key to exit.";
+
+fn render<B: Backend>(f: &mut Frame<B>, game_state: &GameState, actions_state: &mut ActionsState, status: &str) {
   let size = f.size();
   let table_area = Rect {
     x: 0,
     y: 0,
     width: size.width,
-    height: size.height - 15,
+    height: size.height - 16,
   };
 
   draw_table(f, game_state, table_area);
@@ -38,13 +44,29 @@ fn render<B: Backend>(f: &mut Frame<B>, game_state: &GameState, actions_state: &
     .constraints([Constraint::Length(80), Constraint::Min(0)].as_ref())
     .split(Rect {
       x: 0,
-      y: size.height - 15,
+      y: size.height - 16,
       width: size.width,
       height: 15,
     });
 
   draw_player_info(f, game_state, chunks[1]);
   actions_state.render(f, chunks[1]);
+
+  let footer = Paragraph::new(status).alignment(Alignment::Center);
+  f.render_widget(
+    footer,
+    Rect {
+      x: 0,
+      y: size.height - 1,
+      width: size.width,
+      height: 1,
+    },
+  );
+}
+
+fn render_status<B: Backend>(f: &mut Frame<B>, status: &str) {
+  let status = Paragraph::new(status).alignment(Alignment::Center);
+  f.render_widget(status, f.size());
 }
 
 struct DisableRawMode;
@@ -54,67 +76,114 @@ impl Drop for DisableRawMode {
   }
 }
 
-pub fn run_tui() -> Result<(), io::Error> {
-  let _disable_raw_mode = DisableRawMode;
+// Returns false when the player asks to quit.
+fn handle_input(actions_state: &mut ActionsState) -> Result<bool, io::Error> {
+  if event::poll(Duration::from_millis(500))? {
+    if let Event::Key(key) = event::read()? {
+      match key.code {
+        KeyCode::Char('q') => return Ok(false),
+        _ => {
+          actions_state.handle_keypress(key.code);
+          actions_state.send_locked_action();
+        }
+      }
+    }
+  }
+  Ok(true)
+}
 
-  enable_raw_mode()?;
-  let mut stdout = io::stdout();
-  execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-  let backend = CrosstermBackend::new(stdout);
-  let mut terminal = Terminal::new(backend)?;
+// Leaves the last frame on screen until the player has seen it.
+fn wait_for_key() -> Result<(), io::Error> {
+  loop {
+    if let Event::Key(_) = event::read()? {
+      return Ok(());
+    }
+  }
+}
 
-  let mut calling_players = vec![
-    CallingPlayer { id: 1 },
-    CallingPlayer { id: 2 },
-    CallingPlayer { id: 3 },
-    CallingPlayer { id: 4 },
-    CallingPlayer { id: 5 },
-    CallingPlayer { id: 6 },
-    CallingPlayer { id: 7 },
-    CallingPlayer { id: 8 },
-  ];
-
-  let players = calling_players
-    .iter_mut()
-    .map(|p| Box::new(p as &mut dyn Player))
-    .collect::<Vec<Box<&mut dyn Player>>>();
+fn run_local<B: Backend>(terminal: &mut Terminal<B>, actions_state: &mut ActionsState) -> Result<(), io::Error> {
+  let mut players: Vec<Box<dyn Player>> = vec![Box::new(actions_state.create_player())];
+  for id in 2..=8 {
+    players.push(Box::new(CallingPlayer { id }));
+  }
 
   let mut game = Game::create(8, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::from_secs(30),
+    time_bank: Duration::from_secs(60),
+  });
 
-  let mut actions_state = ActionsState::new();
+  let mut runner = GameRunner::create(game, players);
 
   loop {
-    let game_state = game.get_state(Some(0));
+    let is_over = runner.poll().is_none();
+    let game_state = runner.get_game().get_view(Viewer::Player(0));
     actions_state.update_game_state(&game_state);
 
-    terminal.draw(|f| render(f, &game_state, &mut actions_state))?;
+    let status = if is_over {
+      format!("The game is over. {}", EXIT_PROMPT)
+    } else {
+      String::new()
+    };
+    terminal.draw(|f| render(f, &game_state, actions_state, &status))?;
 
-    if let Some(curr_index) = game.get_current_player_index() {
-      match curr_index {
-        0 => {
-          if let Some(action) = actions_state.get_betting_action() {
-            game.action_current_player(action).unwrap();
-          }
-        }
-        _ => {
-          let action = players[curr_index as usize].request_action(game.get_state(Some(curr_index)));
-          game.action_current_player(action).unwrap();
-        }
-      }
+    if is_over {
+      return wait_for_key();
     }
-    let phase = game.next();
-    if phase.is_none() {
-      break;
+    if !handle_input(actions_state)? {
+      return Ok(());
     }
+  }
+}
 
-    if event::poll(Duration::from_millis(500))? {
-      if let Event::Key(key) = event::read()? {
-        match key.code {
-          KeyCode::Char('q') => break,
-          _ => actions_state.handle_keypress(key.code),
-        }
+fn run_remote<B: Backend>(
+  terminal: &mut Terminal<B>,
+  actions_state: &mut ActionsState,
+  address: &str,
+) -> Result<(), io::Error> {
+  let mut table = RemoteTable::connect(address, actions_state.create_player())?;
+
+  loop {
+    let is_over = !table.poll()?;
+    let status = if is_over {
+      format!("{} {}", table.get_status(), EXIT_PROMPT)
+    } else {
+      table.get_status().to_string()
+    };
+
+    match table.get_game_state() {
+      Some(game_state) => {
+        actions_state.update_game_state(game_state);
+        terminal.draw(|f| render(f, game_state, actions_state, &status))?;
+      }
+      None => {
+        terminal.draw(|f| render_status(f, &status))?;
       }
     }
+
+    if is_over {
+      return wait_for_key();
+    }
+    if !handle_input(actions_state)? {
+      return Ok(());
+    }
+  }
+}
+
+pub fn run_tui(address: Option<String>) -> Result<(), io::Error> {
+  let _disable_raw_mode = DisableRawMode;
+
+  enable_raw_mode()?;
+  let mut stdout = io::stdout();
+  execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+  let backend = CrosstermBackend::new(stdout);
+  let mut terminal = Terminal::new(backend)?;
+
+  let mut actions_state = ActionsState::new();
+
+  match address {
+    Some(address) => run_remote(&mut terminal, &mut actions_state, &address)?,
+    None => run_local(&mut terminal, &mut actions_state)?,
   }
 
   execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
@@ -1,5 +1,9 @@
 use crossterm::event::KeyCode;
-use rusty_poker_core::game::{BettingAction, GameState};
+use rusty_poker_core::{
+  game::{BettingAction, GameState},
+  player::{ActionSender, PendingAction, Player},
+};
+use std::{cell::RefCell, rc::Rc};
 use tui::{
   backend::Backend,
   layout::Rect,
@@ -14,6 +18,23 @@ pub struct ActionsState {
   action_selection: ListState,
   raise_amount: u32,
   action_locked: bool,
+  action_sender: Rc<RefCell<Option<ActionSender>>>,
+}
+
+pub struct UiPlayer {
+  action_sender: Rc<RefCell<Option<ActionSender>>>,
+}
+
+impl Player for UiPlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    let (sender, pending) = PendingAction::channel();
+    *self.action_sender.borrow_mut() = Some(sender);
+    pending
+  }
+
+  fn on_action_observed(&mut self, _player_index: u8, _action: BettingAction) {
+    *self.action_sender.borrow_mut() = None;
+  }
 }
 
 impl ActionsState {
@@ -23,6 +44,7 @@ impl ActionsState {
       money_in_wallet: 0,
       raise_amount: 0,
       action_locked: false,
+      action_sender: Rc::new(RefCell::new(None)),
     };
     s.action_selection.select(Some(0));
     s
@@ -70,9 +92,15 @@ impl ActionsState {
     }
   }
 
-  pub fn get_betting_action(&mut self) -> Option<BettingAction> {
-    if !self.action_locked {
-      return None;
+  pub fn create_player(&self) -> UiPlayer {
+    UiPlayer {
+      action_sender: self.action_sender.clone(),
+    }
+  }
+
+  pub fn send_locked_action(&mut self) {
+    if !self.action_locked || self.action_sender.borrow().is_none() {
+      return;
     }
     let selected = self.action_selection.selected().unwrap_or(0);
     let action = match selected {
@@ -87,7 +115,7 @@ impl ActionsState {
     self.raise_amount = 0;
     self.action_selection.select(Some(0));
 
-    Some(action)
+    self.action_sender.take().unwrap().send(action);
   }
 
   pub fn render<B: Backend>(&mut self, f: &mut Frame<B>, area: Rect) {
@@ -0,0 +1,131 @@
+use rusty_poker_core::{
+  game::GameState,
+  player::{PendingAction, Player},
+  protocol::{ClientMessage, ServerMessage},
+};
+use std::{
+  io::{self, BufRead, BufReader, Write},
+  net::{TcpStream, ToSocketAddrs},
+  sync::mpsc::{channel, Receiver, TryRecvError},
+  thread,
+};
+
+use super::actions::UiPlayer;
+
+pub struct RemoteTable {
+  writer: TcpStream,
+  messages: Receiver<ServerMessage>,
+  player: UiPlayer,
+  player_index: Option<u8>,
+  game_state: Option<GameState>,
+  pending_action: Option<PendingAction>,
+  status: String,
+}
+
+fn read_messages(stream: TcpStream) -> Receiver<ServerMessage> {
+  let (sender, receiver) = channel();
+  thread::spawn(move || {
+    for line in BufReader::new(stream).lines() {
+      let Ok(line) = line else { break };
+      // Anything the server sends that we do not understand is skipped rather than ending the game.
+      if let Ok(message) = serde_json::from_str(&line) {
+        if sender.send(message).is_err() {
+          break;
+        }
+      }
+    }
+  });
+  receiver
+}
+
+impl RemoteTable {
+  pub fn connect(address: impl ToSocketAddrs, player: UiPlayer) -> io::Result<RemoteTable> {
+    let writer = TcpStream::connect(address)?;
+    let messages = read_messages(writer.try_clone()?);
+    let mut table = RemoteTable {
+      writer,
+      messages,
+      player,
+      player_index: None,
+      game_state: None,
+      pending_action: None,
+      status: String::from("Joining..."),
+    };
+    table.send(&ClientMessage::Join)?;
+    Ok(table)
+  }
+
+  fn send(&mut self, message: &ClientMessage) -> io::Result<()> {
+    writeln!(self.writer, "{}", serde_json::to_string(message).unwrap())
+  }
+
+  pub fn get_game_state(&self) -> Option<&GameState> {
+    self.game_state.as_ref()
+  }
+
+  pub fn get_status(&self) -> &str {
+    &self.status
+  }
+
+  fn handle_message(&mut self, message: ServerMessage) -> bool {
+    match message {
+      ServerMessage::Waiting { seated, needed } => {
+        self.status = format!("Waiting for players ({}/{})...", seated, needed);
+      }
+      ServerMessage::Seated { player_index } => {
+        self.player_index = Some(player_index);
+        self.status = format!("Seated as player {}.", player_index + 1);
+      }
+      ServerMessage::State(state) => {
+        if self.player_index.is_some() && state.current_player_index == self.player_index {
+          self.pending_action = Some(self.player.request_action(state.clone()));
+        }
+        self.game_state = Some(state);
+      }
+      ServerMessage::ActionObserved { player_index, action } => {
+        if Some(player_index) == self.player_index {
+          self.pending_action = None;
+        }
+        self.player.on_action_observed(player_index, action);
+      }
+      ServerMessage::Showdown(hands) => self.player.on_showdown(&hands),
+      ServerMessage::Left { cash_out } => {
+        self.status = format!("Left the table with ${}.", cash_out);
+        return false;
+      }
+      ServerMessage::GameOver => {
+        self.status = String::from("The game is over.");
+        return false;
+      }
+      ServerMessage::Error(error) => self.status = error,
+    }
+    true
+  }
+
+  // Returns false once the game is over or the server has gone away.
+  pub fn poll(&mut self) -> io::Result<bool> {
+    loop {
+      match self.messages.try_recv() {
+        Ok(message) => {
+          if !self.handle_message(message) {
+            return Ok(false);
+          }
+        }
+        Err(TryRecvError::Empty) => break,
+        Err(TryRecvError::Disconnected) => {
+          self.status = String::from("Lost the connection to the server.");
+          return Ok(false);
+        }
+      }
+    }
+
+    if let Some(Ok(Some(action))) = self.pending_action.as_ref().map(|p| p.poll()) {
+      self.pending_action = None;
+      self.send(&ClientMessage::Action(action))?;
+    }
+    Ok(true)
+  }
+}
+
+#[cfg(test)]
+mod tests;
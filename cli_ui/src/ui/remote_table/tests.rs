@@ -0,0 +1,119 @@
+use crossterm::event::KeyCode;
+use rusty_poker_core::game::{BettingAction, Game, Viewer};
+use std::{net::TcpListener, time::Duration};
+
+use super::super::actions::ActionsState;
+use super::*;
+
+struct FakeServer {
+  reader: BufReader<TcpStream>,
+  writer: TcpStream,
+}
+
+impl FakeServer {
+  fn send(&mut self, message: &ServerMessage) {
+    writeln!(self.writer, "{}", serde_json::to_string(message).unwrap()).unwrap();
+  }
+
+  fn receive(&mut self) -> ClientMessage {
+    let mut line = String::new();
+    self.reader.read_line(&mut line).unwrap();
+    serde_json::from_str(&line).unwrap()
+  }
+}
+
+fn connect(actions_state: &ActionsState) -> (RemoteTable, FakeServer) {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let table = RemoteTable::connect(listener.local_addr().unwrap(), actions_state.create_player()).unwrap();
+  let (stream, _) = listener.accept().unwrap();
+  stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+  let server = FakeServer {
+    reader: BufReader::new(stream.try_clone().unwrap()),
+    writer: stream,
+  };
+  (table, server)
+}
+
+fn poll_until(table: &mut RemoteTable, mut done: impl FnMut(&RemoteTable) -> bool) {
+  for _ in 0..500 {
+    assert!(table.poll().unwrap());
+    if done(table) {
+      return;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  panic!("The table never reached the expected state.");
+}
+
+fn get_state_for_turn() -> GameState {
+  let mut game = Game::create(2, 1000);
+  while game.get_current_player_index().is_none() {
+    game.next();
+  }
+  game.get_view(Viewer::Player(game.get_current_player_index().unwrap()))
+}
+
+#[test]
+fn should_join_and_show_the_state_sent_by_the_server() {
+  let actions_state = ActionsState::new();
+  let (mut table, mut server) = connect(&actions_state);
+  assert_eq!(ClientMessage::Join, server.receive());
+
+  server.send(&ServerMessage::Waiting { seated: 1, needed: 2 });
+  poll_until(&mut table, |t| t.get_status() == "Waiting for players (1/2)...");
+  assert!(table.get_game_state().is_none());
+
+  let state = get_state_for_turn();
+  server.send(&ServerMessage::State(state.clone()));
+  poll_until(&mut table, |t| t.get_game_state().is_some());
+  let shown_state = table.get_game_state().unwrap();
+  assert_eq!(state.hand, shown_state.hand);
+  assert_eq!(state.current_player_index, shown_state.current_player_index);
+}
+
+#[test]
+fn should_send_the_chosen_action_on_the_players_turn() {
+  let mut actions_state = ActionsState::new();
+  let (mut table, mut server) = connect(&actions_state);
+  server.receive();
+
+  let state = get_state_for_turn();
+  server.send(&ServerMessage::Seated {
+    player_index: state.current_player_index.unwrap(),
+  });
+  server.send(&ServerMessage::State(state));
+  poll_until(&mut table, |t| t.get_game_state().is_some());
+
+  actions_state.handle_keypress(KeyCode::Down);
+  actions_state.handle_keypress(KeyCode::Enter);
+  actions_state.send_locked_action();
+  table.poll().unwrap();
+  assert_eq!(ClientMessage::Action(BettingAction::Call), server.receive());
+}
+
+#[test]
+fn should_stop_when_the_game_is_over() {
+  let actions_state = ActionsState::new();
+  let (mut table, mut server) = connect(&actions_state);
+  server.receive();
+
+  server.send(&ServerMessage::GameOver);
+  for _ in 0..500 {
+    if !table.poll().unwrap() {
+      assert_eq!("The game is over.", table.get_status());
+      return;
+    }
+    thread::sleep(Duration::from_millis(10));
+  }
+  panic!("The table never stopped.");
+}
+
+#[test]
+fn should_keep_errors_from_the_server_in_the_status() {
+  let actions_state = ActionsState::new();
+  let (mut table, mut server) = connect(&actions_state);
+  server.receive();
+
+  server.send(&ServerMessage::Error(String::from("It is not your turn.")));
+  poll_until(&mut table, |t| t.get_status() == "It is not your turn.");
+}
@@ -117,6 +117,11 @@ fn draw_player<B: Backend>(
       money_in_wallet: player_state.wallet,
       is_folded: player_state.is_folded,
       is_dealer: game_state.dealer_index == player_index,
+      equity: game_state
+        .all_in_equity
+        .iter()
+        .find(|e| e.player_index == player_index)
+        .map(|e| e.equity),
     },
   );
 }
@@ -182,6 +187,21 @@ pub fn draw_player_info<B: Backend>(f: &mut Frame<B>, game: &GameState, area: Re
   }
   text_spans.push(Span::raw("   "));
   text_spans.push(Span::styled(format!("${}", game.wallet), Style::default()));
+  if let Some(time_remaining) = game.time_remaining {
+    text_spans.push(Span::raw("   "));
+    text_spans.push(Span::styled(
+      format!(
+        "{}s (+{}s)",
+        time_remaining.action_time.as_secs(),
+        time_remaining.time_bank.as_secs()
+      ),
+      Style::default().fg(if time_remaining.action_time.is_zero() {
+        Color::Red
+      } else {
+        Color::White
+      }),
+    ));
+  }
 
   let text = vec![Spans::from(text_spans)];
 
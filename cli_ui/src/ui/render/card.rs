@@ -9,7 +9,7 @@ use tui::{
   Frame,
 };
 
-pub fn card_to_span(card: &Card) -> Span {
+pub fn card_to_span(card: &Card) -> Span<'_> {
   let color = match card.suit {
     Suit::Club => Color::Green,
     Suit::Diamond => Color::Magenta,
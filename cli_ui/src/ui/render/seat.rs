@@ -23,6 +23,7 @@ pub struct Seat {
   pub money_in_wallet: u32,
   pub is_folded: bool,
   pub is_dealer: bool,
+  pub equity: Option<f32>,
 }
 
 pub fn render_seat<B: Backend>(f: &mut Frame<B>, area: Rect, seat: Seat) {
@@ -31,7 +32,10 @@ pub fn render_seat<B: Backend>(f: &mut Frame<B>, area: Rect, seat: Seat) {
     Style::default().fg(if seat.is_turn { Color::Yellow } else { Color::White }),
   );
 
-  let money_in_wallet = Span::raw(format!("${}", seat.money_in_wallet));
+  let money_in_wallet = Span::raw(match seat.equity {
+    Some(equity) => format!("${} ({:.0}%)", seat.money_in_wallet, equity * 100.0),
+    None => format!("${}", seat.money_in_wallet),
+  });
 
   let mut status_spans = vec![];
   if !seat.is_folded {
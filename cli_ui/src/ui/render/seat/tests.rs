@@ -25,6 +25,7 @@ fn render_bottom() {
           money_in_wallet: 980,
           is_folded: false,
           is_dealer: false,
+          equity: None,
         },
       );
     })
@@ -57,6 +58,7 @@ fn render_top() {
           money_in_wallet: 980,
           is_folded: false,
           is_dealer: false,
+          equity: None,
         },
       );
     })
@@ -90,6 +92,7 @@ fn render_left() {
           money_in_wallet: 980,
           is_folded: false,
           is_dealer: false,
+          equity: None,
         },
       );
     })
@@ -122,6 +125,7 @@ fn render_right() {
           money_in_wallet: 980,
           is_folded: false,
           is_dealer: false,
+          equity: None,
         },
       );
     })
@@ -146,6 +150,7 @@ fn get_standard_seat() -> Seat {
     money_in_wallet: 980,
     is_folded: false,
     is_dealer: false,
+    equity: None,
   }
 }
 
@@ -207,3 +212,19 @@ fn render_no_money() {
   expected.set_string(0, 4, "         $0         ", Style::default());
   terminal.backend().assert_buffer(&expected);
 }
+
+#[test]
+fn render_all_in_equity() {
+  let backend = TestBackend::new(20, 5);
+  let mut terminal = Terminal::new(backend).unwrap();
+  terminal
+    .draw(|f| {
+      let mut seat = get_standard_seat();
+      seat.equity = Some(0.453);
+      render_seat(f, Rect::new(0, 0, 20, 5), seat);
+    })
+    .unwrap();
+  let mut expected = get_standard_buffer();
+  expected.set_string(0, 4, "     $980 (45%)     ", Style::default());
+  terminal.backend().assert_buffer(&expected);
+}
@@ -1,3 +1,4 @@
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 #[repr(u8)]
@@ -9,6 +10,17 @@ pub enum Suit {
   Club = 3,
 }
 
+impl Suit {
+  pub fn get_code(&self) -> char {
+    match *self {
+      Suit::Heart => 'h',
+      Suit::Diamond => 'd',
+      Suit::Spade => 's',
+      Suit::Club => 'c',
+    }
+  }
+}
+
 impl std::fmt::Display for Suit {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     match *self {
@@ -74,6 +86,25 @@ impl Card {
   pub fn new(suit: Suit, rank: Rank) -> Card {
     Card { rank, suit }
   }
+
+  pub fn get_code(&self) -> String {
+    format!("{}{}", self.rank, self.suit.get_code())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Card {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&self.get_code())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Card {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let code = String::deserialize(deserializer)?;
+    Card::try_from(code.as_str()).map_err(serde::de::Error::custom)
+  }
 }
 
 impl TryFrom<u8> for Card {
@@ -112,6 +143,21 @@ impl TryFrom<u8> for Card {
   }
 }
 
+impl TryFrom<&str> for Card {
+  type Error = &'static str;
+  fn try_from(code: &str) -> Result<Self, Self::Error> {
+    let mut chars = code.chars();
+    let (Some(rank), Some(suit), None) = (chars.next(), chars.next(), chars.next()) else {
+      return Err("A card code must be a rank and a suit.");
+    };
+    let rank = Rank::iter()
+      .find(|r| r.to_string() == rank.to_string())
+      .ok_or("Bad card rank")?;
+    let suit = Suit::iter().find(|s| s.get_code() == suit).ok_or("Bad card suit")?;
+    Ok(Card { suit, rank })
+  }
+}
+
 impl From<Card> for u8 {
   fn from(item: Card) -> Self {
     item.rank as u8 * 4 + item.suit as u8
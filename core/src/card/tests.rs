@@ -24,3 +24,23 @@ fn display_output() {
   assert_eq!(Card::new(Suit::Spade, Rank::Four).to_string(), "♠4");
   assert_eq!(Card::new(Suit::Spade, Rank::Ten).to_string(), "♠T");
 }
+
+#[test]
+fn conversion_to_and_from_codes() {
+  assert_eq!("Jd", Card::new(Suit::Diamond, Rank::Jack).get_code());
+  assert_eq!("Tc", Card::new(Suit::Club, Rank::Ten).get_code());
+  assert_eq!(Card::new(Suit::Spade, Rank::Ace), Card::try_from("As").unwrap());
+  assert_eq!(Card::new(Suit::Heart, Rank::Two), Card::try_from("2h").unwrap());
+  assert!(Card::try_from("1h").is_err());
+  assert!(Card::try_from("Ax").is_err());
+  assert!(Card::try_from("Ahh").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_as_a_code() {
+  let card = Card::new(Suit::Heart, Rank::King);
+  assert_eq!("\"Kh\"", serde_json::to_string(&card).unwrap());
+  assert_eq!(card, serde_json::from_str::<Card>("\"Kh\"").unwrap());
+  assert!(serde_json::from_str::<Card>("\"Kx\"").is_err());
+}
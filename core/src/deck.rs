@@ -22,6 +22,21 @@ impl std::fmt::Display for Deck {
   }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Deck {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(self.get_cards())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Deck {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let cards = Vec::<Card>::deserialize(deserializer)?;
+    Ok(Deck::from_cards(&cards))
+  }
+}
+
 impl Deck {
   pub fn new() -> Deck {
     Deck { value: 0 }
@@ -33,6 +48,12 @@ impl Deck {
     }
   }
 
+  pub fn short_deck() -> Deck {
+    Deck {
+      value: 0x000F_FFFF_FFFF_0000,
+    }
+  }
+
   pub fn from_cards(cards: &Vec<Card>) -> Deck {
     let mut value = 0u64;
     for c in cards {
@@ -89,5 +110,15 @@ impl std::ops::Add for Deck {
   }
 }
 
+impl std::ops::Sub for Deck {
+  type Output = Deck;
+
+  fn sub(self, other: Deck) -> Deck {
+    Deck {
+      value: self.value & !other.value,
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,4 +1,4 @@
-use crate::card::Card;
+use crate::card::{Card, Rank};
 use crate::deck::*;
 
 #[test]
@@ -73,3 +73,32 @@ fn create_from_cards() {
   let deck = Deck::from_cards(&cards);
   assert_eq!(deck.value, (1 << 8) | (1 << 18));
 }
+
+#[test]
+fn deck_subtraction() {
+  let mut deck_a = Deck::new();
+  deck_a.add_card(Card::try_from(8).unwrap());
+  deck_a.add_card(Card::try_from(16).unwrap());
+  let mut deck_b = Deck::new();
+  deck_b.add_card(Card::try_from(16).unwrap());
+  deck_b.add_card(Card::try_from(20).unwrap());
+
+  let remaining_deck = deck_a - deck_b;
+  assert_eq!(remaining_deck.value, 1 << 8);
+}
+
+#[test]
+fn short_deck_excludes_deuces_through_fives() {
+  let deck = Deck::short_deck();
+  assert_eq!(36, deck.get_cards().len());
+  assert!(deck.get_cards().iter().all(|c| c.rank as u8 >= Rank::Six as u8));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_as_a_list_of_cards() {
+  let deck = Deck::from_cards(&vec![Card::try_from("Ah").unwrap(), Card::try_from("Tc").unwrap()]);
+  assert_eq!("[\"Ah\",\"Tc\"]", serde_json::to_string(&deck).unwrap());
+  assert_eq!(deck, serde_json::from_str::<Deck>("[\"Tc\",\"Ah\"]").unwrap());
+  assert_eq!(Deck::new(), serde_json::from_str::<Deck>("[]").unwrap());
+}
@@ -2,11 +2,19 @@ use crate::card::*;
 use crate::deck::*;
 
 mod ck_perfect_hash;
+mod low;
+mod omaha;
 mod preflop_tables;
+mod short_deck;
+mod stud;
 mod two_plus_two;
 mod types;
 
+pub use low::get_low_hand_score;
+pub use omaha::{chance_to_win_omaha, get_omaha_hand_score, get_omaha_low_hand_score, OmahaHand};
 use preflop_tables::*;
+pub use short_deck::{chance_to_win_short_deck, get_short_deck_hand_for_score, get_short_deck_hand_score};
+pub use stud::chance_to_win_stud;
 use types::*;
 
 fn score_to_hand(score: u16) -> Hand {
@@ -17,7 +25,11 @@ fn score_to_hand(score: u16) -> Hand {
   }
 }
 
-fn evaluate_score(cards: [u8; 7]) -> u16 {
+fn evaluate_score(cards: &[u8]) -> u16 {
+  assert!(
+    (5..=7).contains(&cards.len()),
+    "Only hands of 5 to 7 cards can be evaluated."
+  );
   if cfg!(feature = "eval_two_plus_two") {
     two_plus_two::evaluate_score(cards)
   } else {
@@ -25,22 +37,25 @@ fn evaluate_score(cards: [u8; 7]) -> u16 {
   }
 }
 
-fn cards_to_fixed_array(cards: &[Card]) -> [u8; 7] {
-  let mut numeric_vec: Vec<u8> = cards.iter().map(|c| u8::from(*c)).collect();
-  while numeric_vec.len() < 7 {
-    numeric_vec.push(u8::MAX);
-  }
-  numeric_vec[0..7].try_into().unwrap()
+fn cards_to_numeric(cards: &[Card]) -> Vec<u8> {
+  cards.iter().map(|c| u8::from(*c)).collect()
 }
 
-fn iterate_end_game(table_values: &Deck, player_values: &Deck, wins: &mut u32, games: &mut u32) {
+fn iterate_end_game(
+  score_fn: fn(&[u8]) -> u16,
+  deck: &Deck,
+  table_values: &Deck,
+  player_values: &Deck,
+  wins: &mut u32,
+  games: &mut u32,
+) {
   let used_cards = *table_values + *player_values;
-  let available_cards = used_cards.get_available_cards();
+  let available_cards = (*deck - used_cards).get_cards();
 
-  let fixed_arr = cards_to_fixed_array(&table_values.get_cards());
+  let mut fixed_arr = [0u8; 7];
+  fixed_arr[0..5].copy_from_slice(&cards_to_numeric(&table_values.get_cards()));
 
-  let player_hand = cards_to_fixed_array(&used_cards.get_cards());
-  let player_score = evaluate_score(player_hand);
+  let player_score = score_fn(&cards_to_numeric(&used_cards.get_cards()));
 
   for c1 in &available_cards {
     for c2 in &available_cards {
@@ -50,7 +65,7 @@ fn iterate_end_game(table_values: &Deck, player_values: &Deck, wins: &mut u32, g
       let mut opponent_hand = fixed_arr;
       opponent_hand[5] = u8::from(*c1);
       opponent_hand[6] = u8::from(*c2);
-      let opponent_score = evaluate_score(opponent_hand);
+      let opponent_score = score_fn(&opponent_hand);
       *games += 1;
       if player_score >= opponent_score {
         *wins += 1;
@@ -59,39 +74,49 @@ fn iterate_end_game(table_values: &Deck, player_values: &Deck, wins: &mut u32, g
   }
 }
 
-fn iterate_games(table_values: &Deck, player_values: &Deck, wins: &mut u32, games: &mut u32) {
+fn iterate_games(
+  score_fn: fn(&[u8]) -> u16,
+  deck: &Deck,
+  table_values: &Deck,
+  player_values: &Deck,
+  wins: &mut u32,
+  games: &mut u32,
+) {
   let used_cards = *table_values + *player_values;
-  let available_cards = used_cards.get_available_cards();
-  let table_cards_played = 52 - 2 - available_cards.len();
+  let available_cards = (*deck - used_cards).get_cards();
+  let table_cards_played = table_values.get_cards().len();
 
   for c in &available_cards {
     let mut new_table_value = *table_values;
     new_table_value.add_card(*c);
     if table_cards_played + 1 < 5 {
-      iterate_games(&new_table_value, player_values, wins, games);
+      iterate_games(score_fn, deck, &new_table_value, player_values, wins, games);
     } else {
-      iterate_end_game(&new_table_value, player_values, wins, games);
+      iterate_end_game(score_fn, deck, &new_table_value, player_values, wins, games);
     }
   }
 }
 
-pub fn chance_to_win(table: &Deck, hand: &Deck) -> f32 {
+fn chance_to_win_with_deck(score_fn: fn(&[u8]) -> u16, deck: &Deck, table: &Deck, hand: &Deck) -> f32 {
   let mut wins = 0;
   let mut games = 0;
 
   if table.get_cards().len() >= 5 {
-    iterate_end_game(table, hand, &mut wins, &mut games);
+    iterate_end_game(score_fn, deck, table, hand, &mut wins, &mut games);
   } else {
-    iterate_games(table, hand, &mut wins, &mut games);
+    iterate_games(score_fn, deck, table, hand, &mut wins, &mut games);
   }
 
   (wins as f32) / (games as f32)
 }
 
+pub fn chance_to_win(table: &Deck, hand: &Deck) -> f32 {
+  chance_to_win_with_deck(evaluate_score, &Deck::full_deck(), table, hand)
+}
+
 pub fn get_hand_score(table: &Deck, hand: &Deck) -> u16 {
   let combined = *table + *hand;
-  let fixedarr = cards_to_fixed_array(&combined.get_cards());
-  evaluate_score(fixedarr)
+  evaluate_score(&cards_to_numeric(&combined.get_cards()))
 }
 
 pub fn get_hand_for_score(score: u16) -> Hand {
@@ -99,13 +124,14 @@ pub fn get_hand_for_score(score: u16) -> Hand {
 }
 
 pub fn chance_to_win_preflop(hand: &Deck, num_players: u8) -> f32 {
+  let num_players = (num_players as usize).min(PREFLOP_ODDS_SUITED.len() - 1);
   let cards_in_hand = hand.get_cards();
   let rank1 = cards_in_hand[0].rank;
   let rank2 = cards_in_hand[1].rank;
   if cards_in_hand[0].suit == cards_in_hand[1].suit {
-    PREFLOP_ODDS_SUITED[num_players as usize][rank1 as usize][rank2 as usize]
+    PREFLOP_ODDS_SUITED[num_players][rank1 as usize][rank2 as usize]
   } else {
-    PREFLOP_ODDS_UNSUITED[num_players as usize][rank1 as usize][rank2 as usize]
+    PREFLOP_ODDS_UNSUITED[num_players][rank1 as usize][rank2 as usize]
   }
 }
 
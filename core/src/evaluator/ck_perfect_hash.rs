@@ -8,9 +8,9 @@ const SCORE_INVERSION_VALUE: u16 = 7463;
 
 const HASH_QUINARY_ARR_LEN: usize = 13;
 
-fn hash_quinary(arr: &[u8; HASH_QUINARY_ARR_LEN]) -> u32 {
+fn hash_quinary(arr: &[u8; HASH_QUINARY_ARR_LEN], num_cards: u8) -> u32 {
   let mut sum = 0u32;
-  let mut k = 7u8;
+  let mut k = num_cards;
   for i in 0..HASH_QUINARY_ARR_LEN {
     sum += HASH_CALC_TABLE[arr[i] as usize][HASH_QUINARY_ARR_LEN - i - 1][k as usize];
     if arr[i] > k {
@@ -21,25 +21,30 @@ fn hash_quinary(arr: &[u8; HASH_QUINARY_ARR_LEN]) -> u32 {
   sum
 }
 
-pub fn evaluate_score(cards: [u8; 7]) -> u16 {
+pub fn evaluate_score(cards: &[u8]) -> u16 {
   let suit_hash: u16 = cards.iter().map(|c| SUITBIT_BY_ID_TABLE[*c as usize]).sum();
 
   let suit_hash_value = SUITS_HASH_TABLE[suit_hash as usize] as usize;
   if suit_hash_value != 0 {
     let mut suit_binary = [0; 4];
-    for c in cards {
+    for &c in cards {
       suit_binary[(c & 0x3) as usize] |= BINARIES_BY_ID_TABLE[c as usize];
     }
     return SCORE_INVERSION_VALUE - FLUSH_TABLE[suit_binary[suit_hash_value - 1] as usize];
   }
 
   let mut quinary: [u8; HASH_QUINARY_ARR_LEN] = [0; HASH_QUINARY_ARR_LEN];
-  for c in cards {
+  for &c in cards {
     quinary[(c >> 2) as usize] += 1;
   }
 
-  let hash = hash_quinary(&quinary);
-  SCORE_INVERSION_VALUE - NOFLUSH_TABLE[hash as usize]
+  let hash = hash_quinary(&quinary, cards.len() as u8) as usize;
+  let noflush_score = match cards.len() {
+    5 => NOFLUSH5_TABLE[hash],
+    6 => NOFLUSH6_TABLE[hash],
+    _ => NOFLUSH7_TABLE[hash],
+  };
+  SCORE_INVERSION_VALUE - noflush_score
 }
 
 pub fn score_to_hand(score: u16) -> Hand {
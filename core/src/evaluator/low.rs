@@ -0,0 +1,34 @@
+use super::cards_to_numeric;
+use crate::deck::*;
+
+// Low scores follow the same convention as high scores, a better low has a higher score.
+const LOW_SCORE_BASE: u16 = 0x100;
+
+const LOW_HAND_SIZE: u32 = 5;
+
+fn low_rank_bits(cards: &[u8]) -> u16 {
+  cards.iter().fold(0, |bits, c| match c >> 2 {
+    12 => bits | 1,
+    rank if rank <= 6 => bits | 1 << (rank + 1),
+    _ => bits,
+  })
+}
+
+pub(super) fn evaluate_low_score(cards: &[u8]) -> Option<u16> {
+  let mut bits = low_rank_bits(cards);
+  if bits.count_ones() < LOW_HAND_SIZE {
+    return None;
+  }
+  while bits.count_ones() > LOW_HAND_SIZE {
+    bits &= !(1 << (15 - bits.leading_zeros()));
+  }
+  Some(LOW_SCORE_BASE - bits)
+}
+
+pub fn get_low_hand_score(table: &Deck, hand: &Deck) -> Option<u16> {
+  let combined = *table + *hand;
+  evaluate_low_score(&cards_to_numeric(&combined.get_cards()))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,99 @@
+use super::*;
+use crate::card::*;
+
+#[test]
+fn should_qualify_five_unique_cards_eight_or_lower() {
+  let cards = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Eight),
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Spade, Rank::Four),
+    Card::new(Suit::Diamond, Rank::Three),
+    Card::new(Suit::Heart, Rank::Two),
+  ]);
+  assert!(evaluate_low_score(&cards).is_some());
+}
+
+#[test]
+fn should_not_qualify_cards_above_eight() {
+  let cards = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Nine),
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Spade, Rank::Four),
+    Card::new(Suit::Diamond, Rank::Three),
+    Card::new(Suit::Heart, Rank::Two),
+  ]);
+  assert_eq!(None, evaluate_low_score(&cards));
+}
+
+#[test]
+fn should_not_qualify_paired_cards() {
+  let cards = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Seven),
+    Card::new(Suit::Club, Rank::Seven),
+    Card::new(Suit::Spade, Rank::Four),
+    Card::new(Suit::Diamond, Rank::Three),
+    Card::new(Suit::Heart, Rank::Two),
+  ]);
+  assert_eq!(None, evaluate_low_score(&cards));
+}
+
+#[test]
+fn should_rank_the_wheel_as_the_best_low_ignoring_straights_and_flushes() {
+  let wheel = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Heart, Rank::Two),
+    Card::new(Suit::Heart, Rank::Three),
+    Card::new(Suit::Heart, Rank::Four),
+    Card::new(Suit::Heart, Rank::Five),
+  ]);
+  let six_low = cards_to_numeric(&[
+    Card::new(Suit::Club, Rank::Ace),
+    Card::new(Suit::Heart, Rank::Two),
+    Card::new(Suit::Heart, Rank::Three),
+    Card::new(Suit::Heart, Rank::Four),
+    Card::new(Suit::Spade, Rank::Six),
+  ]);
+  assert!(evaluate_low_score(&wheel) > evaluate_low_score(&six_low));
+}
+
+#[test]
+fn should_compare_lows_from_the_highest_card_down() {
+  let eight_six = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Eight),
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Spade, Rank::Five),
+    Card::new(Suit::Diamond, Rank::Four),
+    Card::new(Suit::Heart, Rank::Three),
+  ]);
+  let eight_seven = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Eight),
+    Card::new(Suit::Club, Rank::Seven),
+    Card::new(Suit::Spade, Rank::Ace),
+    Card::new(Suit::Diamond, Rank::Two),
+    Card::new(Suit::Heart, Rank::Three),
+  ]);
+  assert!(evaluate_low_score(&eight_six) > evaluate_low_score(&eight_seven));
+}
+
+#[test]
+fn should_use_the_lowest_five_cards_available() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Eight),
+    Card::new(Suit::Club, Rank::Seven),
+    Card::new(Suit::Spade, Rank::Three),
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Heart, Rank::Two),
+  ]);
+  let hand = Deck::from_cards(&vec![
+    Card::new(Suit::Club, Rank::Ace),
+    Card::new(Suit::Club, Rank::Four),
+  ]);
+  let expected = cards_to_numeric(&[
+    Card::new(Suit::Heart, Rank::Seven),
+    Card::new(Suit::Club, Rank::Four),
+    Card::new(Suit::Spade, Rank::Three),
+    Card::new(Suit::Diamond, Rank::Two),
+    Card::new(Suit::Heart, Rank::Ace),
+  ]);
+  assert_eq!(evaluate_low_score(&expected), get_low_hand_score(&table, &hand));
+}
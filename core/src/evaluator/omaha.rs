@@ -0,0 +1,119 @@
+use super::low::evaluate_low_score;
+use super::{cards_to_numeric, evaluate_score};
+use crate::card::*;
+use crate::deck::*;
+use rand::prelude::*;
+
+const OMAHA_EQUITY_SAMPLES: u32 = 5000;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct OmahaHand {
+  cards: [Card; 4],
+}
+
+impl OmahaHand {
+  pub fn get_cards(&self) -> [Card; 4] {
+    self.cards
+  }
+}
+
+impl TryFrom<&Deck> for OmahaHand {
+  type Error = &'static str;
+  fn try_from(deck: &Deck) -> Result<Self, Self::Error> {
+    let cards: [Card; 4] = deck
+      .get_cards()
+      .try_into()
+      .map_err(|_| "An Omaha hand must have exactly four cards.")?;
+    Ok(OmahaHand { cards })
+  }
+}
+
+impl From<OmahaHand> for Deck {
+  fn from(hand: OmahaHand) -> Deck {
+    Deck::from_cards(&hand.cards.to_vec())
+  }
+}
+
+fn combinations<T: Copy>(items: &[T], size: usize) -> Vec<Vec<T>> {
+  if size == 0 {
+    return vec![vec![]];
+  }
+  let mut result = vec![];
+  for i in 0..items.len() {
+    for mut rest in combinations(&items[i + 1..], size - 1) {
+      rest.insert(0, items[i]);
+      result.push(rest);
+    }
+  }
+  result
+}
+
+fn best_omaha_score(table: &[u8], hand: &[u8]) -> u16 {
+  let table_combos = combinations(table, 3);
+  let mut best_score = 0;
+  for hole_cards in combinations(hand, 2) {
+    for table_cards in &table_combos {
+      let cards = [
+        hole_cards[0],
+        hole_cards[1],
+        table_cards[0],
+        table_cards[1],
+        table_cards[2],
+      ];
+      best_score = best_score.max(evaluate_score(&cards));
+    }
+  }
+  best_score
+}
+
+pub fn get_omaha_hand_score(table: &Deck, hand: &OmahaHand) -> u16 {
+  let table_cards = cards_to_numeric(&table.get_cards());
+  assert!(
+    (3..=5).contains(&table_cards.len()),
+    "Omaha hands need 3 to 5 cards on the table."
+  );
+  best_omaha_score(&table_cards, &cards_to_numeric(&hand.cards))
+}
+
+pub fn get_omaha_low_hand_score(table: &Deck, hand: &OmahaHand) -> Option<u16> {
+  let table_combos = combinations(&cards_to_numeric(&table.get_cards()), 3);
+  let mut best_score = None;
+  for hole_cards in combinations(&cards_to_numeric(&hand.cards), 2) {
+    for table_cards in &table_combos {
+      let cards = [
+        hole_cards[0],
+        hole_cards[1],
+        table_cards[0],
+        table_cards[1],
+        table_cards[2],
+      ];
+      best_score = best_score.max(evaluate_low_score(&cards));
+    }
+  }
+  best_score
+}
+
+pub fn chance_to_win_omaha(table: &Deck, hand: &OmahaHand) -> f32 {
+  let mut rng = thread_rng();
+  let table_cards = cards_to_numeric(&table.get_cards());
+  let hand_cards = cards_to_numeric(&hand.cards);
+  let mut available_cards = cards_to_numeric(&(*table + Deck::from(*hand)).get_available_cards());
+  let cards_to_deal = 5 - table_cards.len();
+
+  let mut wins = 0;
+  for _ in 0..OMAHA_EQUITY_SAMPLES {
+    let (dealt, _) = available_cards.partial_shuffle(&mut rng, cards_to_deal + 4);
+    let mut full_table = table_cards.clone();
+    full_table.extend_from_slice(&dealt[..cards_to_deal]);
+    let opponent_hand = &dealt[cards_to_deal..];
+
+    if best_omaha_score(&full_table, &hand_cards) >= best_omaha_score(&full_table, opponent_hand) {
+      wins += 1;
+    }
+  }
+
+  (wins as f32) / (OMAHA_EQUITY_SAMPLES as f32)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,129 @@
+use super::*;
+use crate::evaluator::get_hand_for_score;
+use crate::evaluator::types::Hand;
+
+fn omaha_hand(cards: Vec<Card>) -> OmahaHand {
+  OmahaHand::try_from(&Deck::from_cards(&cards)).unwrap()
+}
+
+#[test]
+fn should_only_create_hands_with_four_cards() {
+  let three_cards = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Heart, Rank::Queen),
+  ]);
+  assert!(OmahaHand::try_from(&three_cards).is_err());
+
+  let mut four_cards = three_cards;
+  four_cards.add_card(Card::new(Suit::Heart, Rank::Jack));
+  assert_eq!(four_cards, Deck::from(OmahaHand::try_from(&four_cards).unwrap()));
+}
+
+#[test]
+fn should_use_exactly_two_hole_cards_for_a_flush() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Two),
+    Card::new(Suit::Heart, Rank::Seven),
+    Card::new(Suit::Heart, Rank::Nine),
+    Card::new(Suit::Heart, Rank::Jack),
+    Card::new(Suit::Club, Rank::Four),
+  ]);
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Spade, Rank::King),
+    Card::new(Suit::Diamond, Rank::Queen),
+    Card::new(Suit::Club, Rank::Eight),
+  ]);
+  assert_eq!(Hand::HighCard, get_hand_for_score(get_omaha_hand_score(&table, &hand)));
+}
+
+#[test]
+fn should_use_exactly_three_table_cards_for_a_straight() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ten),
+    Card::new(Suit::Club, Rank::Jack),
+    Card::new(Suit::Spade, Rank::Queen),
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Club, Rank::Ace),
+  ]);
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Heart, Rank::Two),
+    Card::new(Suit::Spade, Rank::Three),
+    Card::new(Suit::Diamond, Rank::Four),
+    Card::new(Suit::Club, Rank::Seven),
+  ]);
+  assert_eq!(Hand::HighCard, get_hand_for_score(get_omaha_hand_score(&table, &hand)));
+}
+
+#[test]
+fn should_pick_the_best_combination_of_cards() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Club, Rank::Ace),
+    Card::new(Suit::Spade, Rank::Ace),
+  ]);
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Spade, Rank::Queen),
+    Card::new(Suit::Spade, Rank::King),
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Club, Rank::Seven),
+  ]);
+  assert_eq!(Hand::FullHouse, get_hand_for_score(get_omaha_hand_score(&table, &hand)));
+}
+
+#[test]
+fn should_always_win_with_the_nuts_on_the_river() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ten),
+    Card::new(Suit::Heart, Rank::Jack),
+    Card::new(Suit::Heart, Rank::Queen),
+    Card::new(Suit::Club, Rank::Two),
+    Card::new(Suit::Diamond, Rank::Five),
+  ]);
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Club, Rank::Seven),
+  ]);
+  assert_eq!(1.0, chance_to_win_omaha(&table, &hand));
+}
+
+#[test]
+fn should_calculate_preflop_equity() {
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Spade, Rank::Ace),
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Spade, Rank::King),
+  ]);
+  let odds = chance_to_win_omaha(&Deck::new(), &hand);
+  assert!(odds > 0.5 && odds < 1.0);
+}
+
+#[test]
+fn should_use_exactly_two_hole_cards_for_a_low() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Two),
+    Card::new(Suit::Club, Rank::Three),
+    Card::new(Suit::Spade, Rank::Four),
+    Card::new(Suit::Diamond, Rank::Five),
+    Card::new(Suit::Club, Rank::King),
+  ]);
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Spade, Rank::King),
+    Card::new(Suit::Diamond, Rank::Queen),
+    Card::new(Suit::Club, Rank::Jack),
+  ]);
+  assert_eq!(None, get_omaha_low_hand_score(&table, &hand));
+
+  let hand = omaha_hand(vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Spade, Rank::Eight),
+    Card::new(Suit::Diamond, Rank::Queen),
+    Card::new(Suit::Club, Rank::Jack),
+  ]);
+  assert!(get_omaha_low_hand_score(&table, &hand).is_some());
+}
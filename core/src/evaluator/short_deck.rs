@@ -0,0 +1,106 @@
+use super::types::Hand;
+use super::{cards_to_numeric, chance_to_win_with_deck, ck_perfect_hash};
+use crate::deck::*;
+use rand::prelude::*;
+use std::ops::RangeInclusive;
+
+// Short deck scores reuse the CK perfect hash scores with the full house and flush bands swapped, so a flush beats
+// a full house.
+const FULL_HOUSE_SCORES: RangeInclusive<u16> = 7141..=7296;
+const FLUSH_SCORES: RangeInclusive<u16> = 5864..=7140;
+const SHORT_DECK_FULL_HOUSE_SCORES: RangeInclusive<u16> = 5864..=6019;
+const SHORT_DECK_FLUSH_SCORES: RangeInclusive<u16> = 6020..=7296;
+const FULL_HOUSE_BAND_SIZE: u16 = 156;
+const FLUSH_BAND_SIZE: u16 = 1277;
+
+const SHORT_DECK_PREFLOP_SAMPLES: u32 = 5000;
+
+const ACE_TO_NINE_RANK_BITS: u16 = 1 << 12 | 1 << 7 | 1 << 6 | 1 << 5 | 1 << 4;
+
+fn evaluate_five_cards(cards: [u8; 5]) -> u16 {
+  let rank_bits = cards.iter().fold(0u16, |bits, c| bits | 1 << (c >> 2));
+  // A-6-7-8-9 is the lowest straight, which takes the place of the wheel as deuces through fives are not in play.
+  let cards = if rank_bits == ACE_TO_NINE_RANK_BITS {
+    cards.map(|c| if c >> 2 == 12 { c } else { c - 16 })
+  } else {
+    cards
+  };
+
+  let score = ck_perfect_hash::evaluate_score(&cards);
+  if FULL_HOUSE_SCORES.contains(&score) {
+    score - FLUSH_BAND_SIZE
+  } else if FLUSH_SCORES.contains(&score) {
+    score + FULL_HOUSE_BAND_SIZE
+  } else {
+    score
+  }
+}
+
+fn evaluate_short_deck_score(cards: &[u8]) -> u16 {
+  assert!(
+    (5..=7).contains(&cards.len()),
+    "Only hands of 5 to 7 cards can be evaluated."
+  );
+  let mut best_score = 0;
+  for mask in 0u8..(1 << cards.len()) {
+    if mask.count_ones() != 5 {
+      continue;
+    }
+    let mut five_cards = [0; 5];
+    let mut num_picked = 0;
+    for (i, &c) in cards.iter().enumerate() {
+      if mask & (1 << i) != 0 {
+        five_cards[num_picked] = c;
+        num_picked += 1;
+      }
+    }
+    best_score = best_score.max(evaluate_five_cards(five_cards));
+  }
+  best_score
+}
+
+pub fn get_short_deck_hand_score(table: &Deck, hand: &Deck) -> u16 {
+  let combined = *table + *hand;
+  evaluate_short_deck_score(&cards_to_numeric(&combined.get_cards()))
+}
+
+pub fn get_short_deck_hand_for_score(score: u16) -> Hand {
+  if SHORT_DECK_FLUSH_SCORES.contains(&score) {
+    Hand::Flush
+  } else if SHORT_DECK_FULL_HOUSE_SCORES.contains(&score) {
+    Hand::FullHouse
+  } else {
+    ck_perfect_hash::score_to_hand(score)
+  }
+}
+
+// Every board can not be played out before the flop in a reasonable time, so the preflop chance is sampled instead.
+fn chance_to_win_short_deck_preflop(hand: &Deck) -> f32 {
+  let mut rng = thread_rng();
+  let hand_cards = cards_to_numeric(&hand.get_cards());
+  let mut available_cards = cards_to_numeric(&(Deck::short_deck() - *hand).get_cards());
+
+  let mut wins = 0;
+  for _ in 0..SHORT_DECK_PREFLOP_SAMPLES {
+    let (dealt, _) = available_cards.partial_shuffle(&mut rng, 7);
+    let mut player_cards = dealt[..5].to_vec();
+    player_cards.extend_from_slice(&hand_cards);
+    let opponent_cards = &dealt[..];
+
+    if evaluate_short_deck_score(&player_cards) >= evaluate_short_deck_score(opponent_cards) {
+      wins += 1;
+    }
+  }
+
+  (wins as f32) / (SHORT_DECK_PREFLOP_SAMPLES as f32)
+}
+
+pub fn chance_to_win_short_deck(table: &Deck, hand: &Deck) -> f32 {
+  if table.get_cards().is_empty() {
+    return chance_to_win_short_deck_preflop(hand);
+  }
+  chance_to_win_with_deck(evaluate_short_deck_score, &Deck::short_deck(), table, hand)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+use super::*;
+use crate::card::*;
+
+#[test]
+fn should_rank_flushes_above_full_houses() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Six),
+    Card::new(Suit::Heart, Rank::Eight),
+    Card::new(Suit::Heart, Rank::Jack),
+  ]);
+  let flush = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Seven),
+    Card::new(Suit::Heart, Rank::King),
+  ]);
+  let full_house_table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Six),
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Heart, Rank::Ace),
+  ]);
+  let full_house = Deck::from_cards(&vec![
+    Card::new(Suit::Spade, Rank::Ace),
+    Card::new(Suit::Diamond, Rank::Ace),
+  ]);
+
+  let flush_score = get_short_deck_hand_score(&table, &flush);
+  let full_house_score = get_short_deck_hand_score(&full_house_table, &full_house);
+  assert_eq!(Hand::Flush, get_short_deck_hand_for_score(flush_score));
+  assert_eq!(Hand::FullHouse, get_short_deck_hand_for_score(full_house_score));
+  assert!(flush_score > full_house_score);
+}
+
+#[test]
+fn should_still_rank_four_of_a_kind_above_flushes() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Six),
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Spade, Rank::Six),
+  ]);
+  let hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Six),
+    Card::new(Suit::Heart, Rank::King),
+  ]);
+  let score = get_short_deck_hand_score(&table, &hand);
+  assert_eq!(Hand::FourOfAKind, get_short_deck_hand_for_score(score));
+  assert!(score > *SHORT_DECK_FLUSH_SCORES.end());
+}
+
+#[test]
+fn should_treat_ace_to_nine_as_the_lowest_straight() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Six),
+    Card::new(Suit::Club, Rank::Seven),
+    Card::new(Suit::Spade, Rank::Eight),
+  ]);
+  let wheel = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Nine),
+    Card::new(Suit::Heart, Rank::Ace),
+  ]);
+  let six_to_ten = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Nine),
+    Card::new(Suit::Heart, Rank::Ten),
+  ]);
+
+  let wheel_score = get_short_deck_hand_score(&table, &wheel);
+  assert_eq!(Hand::Straight, get_short_deck_hand_for_score(wheel_score));
+  assert!(get_short_deck_hand_score(&table, &six_to_ten) > wheel_score);
+}
+
+#[test]
+fn should_treat_suited_ace_to_nine_as_a_straight_flush() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Club, Rank::Seven),
+    Card::new(Suit::Club, Rank::Eight),
+  ]);
+  let hand = Deck::from_cards(&vec![
+    Card::new(Suit::Club, Rank::Nine),
+    Card::new(Suit::Club, Rank::Ace),
+  ]);
+  let score = get_short_deck_hand_score(&table, &hand);
+  assert_eq!(Hand::StraightFlush, get_short_deck_hand_for_score(score));
+}
+
+#[test]
+fn should_calculate_chance_to_win_on_the_river() {
+  let table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ten),
+    Card::new(Suit::Heart, Rank::Jack),
+    Card::new(Suit::Heart, Rank::Queen),
+    Card::new(Suit::Club, Rank::Six),
+    Card::new(Suit::Diamond, Rank::Nine),
+  ]);
+  let nuts = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Heart, Rank::Ace),
+  ]);
+  assert_eq!(1.0, chance_to_win_short_deck(&table, &nuts));
+
+  let weak = Deck::from_cards(&vec![
+    Card::new(Suit::Club, Rank::Seven),
+    Card::new(Suit::Spade, Rank::Eight),
+  ]);
+  let odds = chance_to_win_short_deck(&table, &weak);
+  assert!(odds > 0.0 && odds < 1.0);
+}
+
+#[test]
+fn should_calculate_chance_to_win_before_the_flop() {
+  let aces = Deck::from_cards(&vec![
+    Card::new(Suit::Club, Rank::Ace),
+    Card::new(Suit::Spade, Rank::Ace),
+  ]);
+  let odds = chance_to_win_short_deck(&Deck::new(), &aces);
+  assert!(odds > 0.6 && odds < 1.0);
+}
@@ -0,0 +1,34 @@
+use super::{cards_to_numeric, evaluate_score};
+use crate::deck::*;
+use rand::prelude::*;
+
+const STUD_EQUITY_SAMPLES: u32 = 5000;
+
+const STUD_HAND_SIZE: usize = 7;
+
+pub fn chance_to_win_stud(table: &Deck, hand: &Deck) -> f32 {
+  let mut rng = thread_rng();
+  let table_cards = cards_to_numeric(&table.get_cards());
+  let hand_cards = cards_to_numeric(&hand.get_cards());
+  let mut available_cards = cards_to_numeric(&(*table + *hand).get_available_cards());
+  let cards_per_player = STUD_HAND_SIZE - table_cards.len();
+  let cards_to_deal = cards_per_player - hand_cards.len();
+
+  let mut wins = 0;
+  for _ in 0..STUD_EQUITY_SAMPLES {
+    let (dealt, _) = available_cards.partial_shuffle(&mut rng, cards_to_deal + cards_per_player);
+
+    let mut player_cards = table_cards.clone();
+    player_cards.extend_from_slice(&hand_cards);
+    player_cards.extend_from_slice(&dealt[..cards_to_deal]);
+
+    let mut opponent_cards = table_cards.clone();
+    opponent_cards.extend_from_slice(&dealt[cards_to_deal..]);
+
+    if evaluate_score(&player_cards) >= evaluate_score(&opponent_cards) {
+      wins += 1;
+    }
+  }
+
+  (wins as f32) / (STUD_EQUITY_SAMPLES as f32)
+}
@@ -13,7 +13,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Diamond, Rank::Four)),
         u8::from(Card::new(Suit::Heart, Rank::Five)),
       ];
-      assert_eq!(Hand::StraightFlush, $handfn($scorefn(cards)));
+      assert_eq!(Hand::StraightFlush, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -27,7 +27,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Four)),
         u8::from(Card::new(Suit::Club, Rank::Five)),
       ];
-      assert_eq!(Hand::StraightFlush, $handfn($scorefn(cards)));
+      assert_eq!(Hand::StraightFlush, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -41,7 +41,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Four)),
         u8::from(Card::new(Suit::Club, Rank::Five)),
       ];
-      assert_eq!(Hand::StraightFlush, $handfn($scorefn(cards)));
+      assert_eq!(Hand::StraightFlush, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -55,7 +55,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Nine)),
         u8::from(Card::new(Suit::Club, Rank::Five)),
       ];
-      assert_eq!(Hand::FourOfAKind, $handfn($scorefn(cards)));
+      assert_eq!(Hand::FourOfAKind, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -72,11 +72,11 @@ macro_rules! evaluator_correctness_tests {
 
       let mut loser = winner;
       loser[4] = u8::from(Card::new(Suit::Club, Rank::Jack));
-      assert_eq!(true, $scorefn(winner) > $scorefn(loser));
+      assert_eq!(true, $scorefn(&winner) > $scorefn(&loser));
 
       let mut equal_winner = winner;
       equal_winner[6] = u8::from(Card::new(Suit::Club, Rank::Two));
-      assert_eq!($scorefn(winner), $scorefn(equal_winner));
+      assert_eq!($scorefn(&winner), $scorefn(&equal_winner));
     }
 
     #[test]
@@ -90,7 +90,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Nine)),
         u8::from(Card::new(Suit::Club, Rank::Five)),
       ];
-      assert_eq!(Hand::FullHouse, $handfn($scorefn(cards)));
+      assert_eq!(Hand::FullHouse, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -104,7 +104,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Nine)),
         u8::from(Card::new(Suit::Club, Rank::Five)),
       ];
-      assert_eq!(Hand::Flush, $handfn($scorefn(cards)));
+      assert_eq!(Hand::Flush, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -118,7 +118,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Jack)),
         u8::from(Card::new(Suit::Club, Rank::Nine)),
       ];
-      assert_eq!(Hand::Straight, $handfn($scorefn(cards)));
+      assert_eq!(Hand::Straight, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -132,7 +132,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Jack)),
         u8::from(Card::new(Suit::Club, Rank::Nine)),
       ];
-      assert_eq!(Hand::Straight, $handfn($scorefn(cards)));
+      assert_eq!(Hand::Straight, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -146,7 +146,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Spade, Rank::Eight)),
         u8::from(Card::new(Suit::Club, Rank::Ten)),
       ];
-      assert_eq!(Hand::ThreeOfAKind, $handfn($scorefn(cards)));
+      assert_eq!(Hand::ThreeOfAKind, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -164,7 +164,7 @@ macro_rules! evaluator_correctness_tests {
       for i in 3..5 {
         let mut loser = winner;
         loser[i] = u8::from(Card::new(Suit::Club, Rank::Two));
-        assert_eq!(true, $scorefn(winner) > $scorefn(loser));
+        assert_eq!(true, $scorefn(&winner) > $scorefn(&loser));
       }
     }
 
@@ -179,7 +179,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Diamond, Rank::Five)),
         u8::from(Card::new(Suit::Spade, Rank::Eight)),
       ];
-      assert_eq!(Hand::TwoPairs, $handfn($scorefn(cards)));
+      assert_eq!(Hand::TwoPairs, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -196,11 +196,11 @@ macro_rules! evaluator_correctness_tests {
 
       let mut loser = winner;
       loser[4] = u8::from(Card::new(Suit::Club, Rank::Two));
-      assert_eq!(true, $scorefn(winner) > $scorefn(loser));
+      assert_eq!(true, $scorefn(&winner) > $scorefn(&loser));
 
       let mut equal_winner = winner;
       equal_winner[5] = u8::from(Card::new(Suit::Club, Rank::Two));
-      assert_eq!($scorefn(winner), $scorefn(equal_winner));
+      assert_eq!($scorefn(&winner), $scorefn(&equal_winner));
     }
 
     #[test]
@@ -214,7 +214,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Diamond, Rank::Five)),
         u8::from(Card::new(Suit::Spade, Rank::Two)),
       ];
-      assert_eq!(Hand::Pair, $handfn($scorefn(cards)));
+      assert_eq!(Hand::Pair, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -232,7 +232,7 @@ macro_rules! evaluator_correctness_tests {
       for i in 2..5 {
         let mut loser = winner;
         loser[i] = u8::from(Card::new(Suit::Diamond, Rank::Two));
-        assert_eq!(true, $scorefn(winner) > $scorefn(loser));
+        assert_eq!(true, $scorefn(&winner) > $scorefn(&loser));
       }
     }
 
@@ -247,7 +247,7 @@ macro_rules! evaluator_correctness_tests {
         u8::from(Card::new(Suit::Diamond, Rank::Six)),
         u8::from(Card::new(Suit::Spade, Rank::Five)),
       ];
-      assert_eq!(Hand::HighCard, $handfn($scorefn(cards)));
+      assert_eq!(Hand::HighCard, $handfn($scorefn(&cards)));
     }
 
     #[test]
@@ -264,11 +264,85 @@ macro_rules! evaluator_correctness_tests {
       for i in 0..5 {
         let mut loser = winner;
         loser[i] = u8::from(Card::new(Suit::Diamond, Rank::Two));
-        assert_eq!(true, $scorefn(winner) > $scorefn(loser));
+        assert_eq!(true, $scorefn(&winner) > $scorefn(&loser));
       }
       let mut equal_winner = winner;
       equal_winner[5] = u8::from(Card::new(Suit::Diamond, Rank::Two));
-      assert_eq!($scorefn(winner), $scorefn(equal_winner));
+      assert_eq!($scorefn(&winner), $scorefn(&equal_winner));
+    }
+
+    #[test]
+    fn evaluator_identifies_five_card_hands() {
+      let cards = [
+        u8::from(Card::new(Suit::Diamond, Rank::Ten)),
+        u8::from(Card::new(Suit::Club, Rank::Ten)),
+        u8::from(Card::new(Suit::Spade, Rank::Ten)),
+        u8::from(Card::new(Suit::Heart, Rank::Four)),
+        u8::from(Card::new(Suit::Club, Rank::Four)),
+      ];
+      assert_eq!(Hand::FullHouse, $handfn($scorefn(&cards)));
+
+      let cards = [
+        u8::from(Card::new(Suit::Spade, Rank::Two)),
+        u8::from(Card::new(Suit::Spade, Rank::Nine)),
+        u8::from(Card::new(Suit::Spade, Rank::Jack)),
+        u8::from(Card::new(Suit::Spade, Rank::Four)),
+        u8::from(Card::new(Suit::Spade, Rank::Seven)),
+      ];
+      assert_eq!(Hand::Flush, $handfn($scorefn(&cards)));
+
+      let cards = [
+        u8::from(Card::new(Suit::Diamond, Rank::King)),
+        u8::from(Card::new(Suit::Club, Rank::Queen)),
+        u8::from(Card::new(Suit::Spade, Rank::Jack)),
+        u8::from(Card::new(Suit::Heart, Rank::Nine)),
+        u8::from(Card::new(Suit::Club, Rank::Eight)),
+      ];
+      assert_eq!(Hand::HighCard, $handfn($scorefn(&cards)));
+    }
+
+    #[test]
+    fn evaluator_identifies_six_card_hands() {
+      let cards = [
+        u8::from(Card::new(Suit::Diamond, Rank::Six)),
+        u8::from(Card::new(Suit::Club, Rank::Seven)),
+        u8::from(Card::new(Suit::Spade, Rank::Eight)),
+        u8::from(Card::new(Suit::Heart, Rank::Nine)),
+        u8::from(Card::new(Suit::Club, Rank::Ten)),
+        u8::from(Card::new(Suit::Club, Rank::Two)),
+      ];
+      assert_eq!(Hand::Straight, $handfn($scorefn(&cards)));
+
+      let cards = [
+        u8::from(Card::new(Suit::Diamond, Rank::Six)),
+        u8::from(Card::new(Suit::Club, Rank::Six)),
+        u8::from(Card::new(Suit::Spade, Rank::Eight)),
+        u8::from(Card::new(Suit::Heart, Rank::Eight)),
+        u8::from(Card::new(Suit::Club, Rank::Ten)),
+        u8::from(Card::new(Suit::Club, Rank::Two)),
+      ];
+      assert_eq!(Hand::TwoPairs, $handfn($scorefn(&cards)));
+    }
+
+    #[test]
+    fn evaluator_scores_the_same_hand_equally_regardless_of_card_count() {
+      let five_cards = [
+        u8::from(Card::new(Suit::Diamond, Rank::Ace)),
+        u8::from(Card::new(Suit::Club, Rank::Ace)),
+        u8::from(Card::new(Suit::Spade, Rank::King)),
+        u8::from(Card::new(Suit::Heart, Rank::Queen)),
+        u8::from(Card::new(Suit::Club, Rank::Nine)),
+      ];
+      let mut six_cards = [0; 6];
+      six_cards[0..5].copy_from_slice(&five_cards);
+      six_cards[5] = u8::from(Card::new(Suit::Heart, Rank::Two));
+      let mut seven_cards = [0; 7];
+      seven_cards[0..6].copy_from_slice(&six_cards);
+      seven_cards[6] = u8::from(Card::new(Suit::Spade, Rank::Three));
+
+      assert_eq!(Hand::Pair, $handfn($scorefn(&five_cards)));
+      assert_eq!($scorefn(&five_cards), $scorefn(&six_cards));
+      assert_eq!($scorefn(&five_cards), $scorefn(&seven_cards));
     }
   };
 }
@@ -2,27 +2,42 @@ use super::*;
 use ntest::assert_true;
 
 #[test]
-fn create_a_fixed_array_from_card_vec() {
-  let arr = cards_to_fixed_array(&vec![
+fn should_score_hands_on_the_flop() {
+  let table = Deck::from_cards(&vec![
     Card::new(Suit::Heart, Rank::Two),
-    Card::new(Suit::Heart, Rank::Three),
+    Card::new(Suit::Club, Rank::Two),
+    Card::new(Suit::Spade, Rank::Nine),
   ]);
-  assert_eq!(arr, [0, 4, 255, 255, 255, 255, 255]);
+  let hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Nine),
+    Card::new(Suit::Heart, Rank::King),
+  ]);
+  assert_eq!(Hand::TwoPairs, get_hand_for_score(get_hand_score(&table, &hand)));
 }
 
 #[test]
-fn create_a_fixed_array_too_many_elements() {
-  let arr = cards_to_fixed_array(&vec![
+fn should_score_hands_on_the_turn() {
+  let table = Deck::from_cards(&vec![
     Card::new(Suit::Heart, Rank::Two),
-    Card::new(Suit::Heart, Rank::Three),
-    Card::new(Suit::Heart, Rank::Four),
+    Card::new(Suit::Heart, Rank::Jack),
+    Card::new(Suit::Spade, Rank::Nine),
     Card::new(Suit::Heart, Rank::Five),
-    Card::new(Suit::Heart, Rank::Six),
-    Card::new(Suit::Heart, Rank::Seven),
-    Card::new(Suit::Heart, Rank::Eight),
+  ]);
+  let hand = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Nine),
+    Card::new(Suit::Heart, Rank::King),
+  ]);
+  assert_eq!(Hand::Flush, get_hand_for_score(get_hand_score(&table, &hand)));
+}
+
+#[test]
+#[should_panic]
+fn should_not_score_hands_with_less_than_five_cards() {
+  let hand = Deck::from_cards(&vec![
     Card::new(Suit::Heart, Rank::Nine),
+    Card::new(Suit::Heart, Rank::King),
   ]);
-  assert_eq!(arr, [0, 4, 8, 12, 16, 20, 24]);
+  get_hand_score(&Deck::new(), &hand);
 }
 
 #[test]
@@ -34,6 +49,15 @@ fn should_calculate_preflop_odds_unsuited() {
   assert_true!(chance_to_win_preflop(&hand, 2) > 0.0);
 }
 
+#[test]
+fn should_use_the_largest_preflop_table_for_more_players() {
+  let hand = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Spade, Rank::Ace),
+  ]);
+  assert_eq!(chance_to_win_preflop(&hand, 8), chance_to_win_preflop(&hand, 12));
+}
+
 #[test]
 fn should_calculate_preflop_odds_suited() {
   let hand = Deck::from_cards(&vec![
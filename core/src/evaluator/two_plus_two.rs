@@ -14,19 +14,22 @@ fn init_two_plus_two_table() {
     let mut file = File::open("../HandRanks.dat").expect("File not found");
     unsafe {
       file
-        .read_u32_into::<LittleEndian>(&mut HAND_RANKS)
+        .read_u32_into::<LittleEndian>(&mut *std::ptr::addr_of_mut!(HAND_RANKS))
         .expect("Could not read the file.");
     }
   });
 }
 
-pub fn evaluate_score(cards: [u8; 7]) -> u16 {
+pub fn evaluate_score(cards: &[u8]) -> u16 {
   init_two_plus_two_table();
-  let mut p;
+  let mut p = 53;
   unsafe {
-    p = HAND_RANKS[53 + cards[0] as usize + 1];
-    for i in 1..=6 {
-      p = HAND_RANKS[(p as usize) + cards[i] as usize + 1];
+    for &c in cards {
+      p = HAND_RANKS[(p as usize) + c as usize + 1];
+    }
+    // Hands shorter than 7 cards need one more lookup to resolve the final state into a score.
+    if cards.len() < 7 {
+      p = HAND_RANKS[p as usize];
     }
   }
   u16::try_from(p).unwrap_or_default()
@@ -1,5 +1,6 @@
 #[repr(u8)]
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Hand {
   Invalid = 0,
   HighCard = 1,
@@ -1,25 +1,56 @@
 mod betting_round;
+mod clock;
+mod snapshot;
 
 use crate::card::*;
 use crate::deck::Deck;
-use crate::evaluator::*;
+use crate::variant::{ForcedBets, Holdem, Street, Variant};
 use betting_round::*;
 use rand::prelude::*;
+use rand_chacha::ChaCha12Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use snapshot::GameSnapshot;
 
 #[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Phase {
   Init = 0,
   PreFlop = 1,
   Flop = 2,
   Turn = 3,
   River = 4,
-  Showdown = 5,
+  ThirdStreet = 5,
+  FourthStreet = 6,
+  FifthStreet = 7,
+  SixthStreet = 8,
+  SeventhStreet = 9,
+  Showdown = 10,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SeatStatus {
+  Playing,
+  SittingOut,
+  Left,
+  Eliminated,
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Seat {
   player_index: u8,
+  status: SeatStatus,
   hand: Deck,
+  visible_cards: Deck,
   wallet: u32,
+  time_bank: Duration,
+  missed_small_blind: bool,
+  missed_big_blind: bool,
+  muck_losing_hands: bool,
 }
 
 pub struct Game {
@@ -28,18 +59,115 @@ pub struct Game {
   table: Deck,
   betting_round: BettingRound,
   dealer_index: u8,
+  dead_button: Option<u8>,
+  blind_positions: Option<BlindPositions>,
   blind: u32,
   active_seats: Vec<Seat>,
+  inactive_seats: Vec<Seat>,
+  variant: Arc<dyn Variant>,
+  dealt_streets: usize,
+  time_limit: Option<TimeLimit>,
+  clock: Arc<dyn Clock>,
+  turn_started_at: Duration,
+  carried_turn_time: Duration,
+  showdown: Vec<ShowdownHand>,
+  rng: ChaCha12Rng,
+  run_it_times: u8,
+  runouts: Vec<Runout>,
+  all_in_equity: Vec<PlayerEquity>,
+  rake: Option<Rake>,
+  hand_rake: u32,
+  total_rake: u32,
+  action_log: Vec<LoggedAction>,
+}
+
+struct LoggedAction {
+  player_index: u8,
+  action: BettingAction,
+  snapshot: GameSnapshot,
+}
+
+const ALL_IN_EQUITY_SAMPLES: u32 = 1000;
+
+#[derive(Clone)]
+struct DealtCards {
+  available_cards: Deck,
+  table: Deck,
+  hands: Vec<Deck>,
+  visible_cards: Vec<Deck>,
+  dealt_streets: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rake {
+  pub percentage: f32,
+  pub cap: u32,
+  pub no_flop_no_drop: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerEquity {
+  pub player_index: u8,
+  pub equity: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Runout {
+  pub table: Deck,
+  pub hands: Vec<ShowdownHand>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShowdownHand {
+  pub player_index: u8,
+  pub hand: Deck,
+  pub score: u16,
+  pub winnings: u32,
+  pub is_mucked: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct BlindPositions {
+  small_blind: u8,
+  big_blind: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeLimit {
+  pub action_time: Duration,
+  pub time_bank: Duration,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRemaining {
+  pub action_time: Duration,
+  pub time_bank: Duration,
 }
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerState {
+  pub player_index: u8,
+  pub is_eliminated: bool,
+  pub is_sitting_out: bool,
+  pub has_left: bool,
+  pub missed_blinds: u32,
   pub is_folded: bool,
   pub wallet: u32,
   pub money_on_table: u32,
+  pub visible_cards: Deck,
+  pub hole_cards: Option<Deck>,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameState {
   pub total_pot: u32,
   pub table: Deck,
@@ -50,8 +178,57 @@ pub struct GameState {
   pub hand: Deck,
   pub wallet: u32,
   pub value_to_call: u32,
+  pub time_remaining: Option<TimeRemaining>,
+  pub all_in_equity: Vec<PlayerEquity>,
+  pub remaining_deck: Option<Deck>,
+  #[cfg_attr(
+    feature = "serde",
+    serde(serialize_with = "serialize_variant", deserialize_with = "deserialize_variant")
+  )]
+  pub variant: Arc<dyn Variant>,
+}
+
+impl GameState {
+  pub fn get_num_players_in_hand(&self) -> u8 {
+    self
+      .players
+      .iter()
+      .filter(|p| !p.is_eliminated && !p.is_sitting_out && !p.has_left && !p.is_folded)
+      .count() as u8
+  }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_variant<S: serde::Serializer>(variant: &Arc<dyn Variant>, serializer: S) -> Result<S::Ok, S::Error> {
+  serializer.serialize_str(variant.get_name())
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_variant<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Arc<dyn Variant>, D::Error> {
+  let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+  crate::variant::get_variant_by_name(&name).ok_or_else(|| serde::de::Error::custom("Unknown variant"))
+}
+
+// A player view only ever has the hole cards of that player, and only the admin view has every hand and the deck.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Viewer {
+  Spectator,
+  Player(u8),
+  Admin,
+}
+
+impl Viewer {
+  fn can_see_hand_of(&self, player_index: u8) -> bool {
+    match self {
+      Viewer::Spectator => false,
+      Viewer::Player(idx) => *idx == player_index,
+      Viewer::Admin => true,
+    }
+  }
 }
 
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BettingAction {
   Call,
   Fold,
@@ -61,27 +238,280 @@ pub enum BettingAction {
 
 impl Game {
   pub fn create(num_players: u8, initial_wallet: u32) -> Game {
+    Game::create_with_variant(num_players, initial_wallet, Box::new(Holdem))
+  }
+
+  pub fn create_with_variant(num_players: u8, initial_wallet: u32, variant: Box<dyn Variant>) -> Game {
     Game {
       phase: Phase::Init,
-      available_cards: Deck::full_deck(),
+      available_cards: variant.get_deck(),
       table: Deck::new(),
       dealer_index: num_players - 1,
+      dead_button: None,
+      blind_positions: None,
       blind: 20,
       active_seats: (0..num_players)
         .map(|player_index| Seat {
           player_index,
+          status: SeatStatus::Playing,
           hand: Deck::new(),
+          visible_cards: Deck::new(),
           wallet: initial_wallet,
+          time_bank: Duration::ZERO,
+          missed_small_blind: false,
+          missed_big_blind: false,
+          muck_losing_hands: false,
         })
         .collect(),
+      inactive_seats: vec![],
       betting_round: BettingRound::create_for_players(num_players),
+      variant: Arc::from(variant),
+      dealt_streets: 0,
+      time_limit: None,
+      clock: Arc::new(SystemClock::create()),
+      turn_started_at: Duration::ZERO,
+      carried_turn_time: Duration::ZERO,
+      showdown: vec![],
+      rng: ChaCha12Rng::from_entropy(),
+      run_it_times: 1,
+      runouts: vec![],
+      all_in_equity: vec![],
+      rake: None,
+      hand_rake: 0,
+      total_rake: 0,
+      action_log: vec![],
+    }
+  }
+
+  pub fn set_run_it_times(&mut self, times: u8) -> Result<(), &'static str> {
+    if times == 0 {
+      return Err("The board needs to be run at least once.");
+    }
+    self.run_it_times = times;
+    Ok(())
+  }
+
+  pub fn set_rake(&mut self, rake: Option<Rake>) -> Result<(), &'static str> {
+    if let Some(rake) = rake {
+      if !(0.0..=100.0).contains(&rake.percentage) {
+        return Err("Rake percentage must be between 0 and 100.");
+      }
     }
+    self.rake = rake;
+    Ok(())
+  }
+
+  pub fn get_hand_rake(&self) -> u32 {
+    self.hand_rake
+  }
+
+  pub fn get_total_rake(&self) -> u32 {
+    self.total_rake
+  }
+
+  pub fn set_seed(&mut self, seed: u64) {
+    self.rng = ChaCha12Rng::seed_from_u64(seed);
+  }
+
+  pub fn set_time_limit(&mut self, time_limit: TimeLimit) {
+    self.time_limit = Some(time_limit);
+    for seat in self.active_seats.iter_mut() {
+      seat.time_bank = time_limit.time_bank;
+    }
+    self.start_turn();
+  }
+
+  pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+    let turn_time_used = self.get_turn_time_used();
+    self.clock = clock;
+    self.turn_started_at = self.clock.now();
+    self.carried_turn_time = turn_time_used;
+  }
+
+  fn start_turn(&mut self) {
+    self.turn_started_at = self.clock.now();
+    self.carried_turn_time = Duration::ZERO;
+  }
+
+  fn get_turn_time_used(&self) -> Duration {
+    self
+      .clock
+      .now()
+      .saturating_sub(self.turn_started_at)
+      .saturating_add(self.carried_turn_time)
+  }
+
+  pub fn get_blind(&self) -> u32 {
+    self.blind
+  }
+
+  pub fn set_blind(&mut self, blind: u32) -> Result<(), &'static str> {
+    if self.phase != Phase::Init {
+      return Err("The blind can only change between hands.");
+    }
+    self.blind = blind;
+    Ok(())
+  }
+
+  fn check_between_hands(&self) -> Result<(), &'static str> {
+    if self.phase != Phase::Init {
+      return Err("Players can only change seats between hands.");
+    }
+    Ok(())
+  }
+
+  fn activate_seat(&mut self, seat: Seat) {
+    let position = self
+      .active_seats
+      .iter()
+      .position(|s| s.player_index > seat.player_index)
+      .unwrap_or(self.active_seats.len());
+    if position <= self.dealer_index as usize && !self.active_seats.is_empty() {
+      self.dealer_index += 1;
+    }
+    self.active_seats.insert(
+      position,
+      Seat {
+        status: SeatStatus::Playing,
+        ..seat
+      },
+    );
+    self.betting_round = BettingRound::create_for_players(self.active_seats.len() as u8);
+  }
+
+  fn deactivate_seat(&mut self, player_index: u8, status: SeatStatus) -> Result<(), &'static str> {
+    let position = self
+      .active_seats
+      .iter()
+      .position(|s| s.player_index == player_index)
+      .ok_or("That player is not playing.")?;
+    let seat = self.active_seats.remove(position);
+    // The button stays with the seat before the one removed so the next hand still moves it along by one.
+    if position <= self.dealer_index as usize && !self.active_seats.is_empty() {
+      self.dealer_index = ((self.dealer_index as usize + self.active_seats.len() - 1) % self.active_seats.len()) as u8;
+    }
+    self.inactive_seats.push(Seat { status, ..seat });
+    self.betting_round = BettingRound::create_for_players(self.active_seats.len() as u8);
+    Ok(())
+  }
+
+  fn take_inactive_seat(&mut self, player_index: u8) -> Option<Seat> {
+    let position = self
+      .inactive_seats
+      .iter()
+      .position(|s| s.player_index == player_index)?;
+    Some(self.inactive_seats.remove(position))
+  }
+
+  pub fn seat_player(&mut self, buy_in: u32) -> Result<u8, &'static str> {
+    self.check_between_hands()?;
+    if buy_in < self.blind {
+      return Err("The buy in must cover the big blind.");
+    }
+    let player_index = self
+      .active_seats
+      .iter()
+      .chain(self.inactive_seats.iter())
+      .map(|s| s.player_index)
+      .max()
+      .map_or(Some(0), |idx| idx.checked_add(1))
+      .ok_or("No more players can join this game.")?;
+    self.activate_seat(Seat {
+      player_index,
+      status: SeatStatus::Playing,
+      hand: Deck::new(),
+      visible_cards: Deck::new(),
+      wallet: buy_in,
+      time_bank: self.time_limit.map_or(Duration::ZERO, |t| t.time_bank),
+      missed_small_blind: false,
+      missed_big_blind: false,
+      muck_losing_hands: false,
+    });
+    Ok(player_index)
+  }
+
+  pub fn leave(&mut self, player_index: u8) -> Result<u32, &'static str> {
+    self.check_between_hands()?;
+    if self.active_seats.iter().any(|s| s.player_index == player_index) {
+      self.deactivate_seat(player_index, SeatStatus::Left)?;
+    } else {
+      self
+        .inactive_seats
+        .iter_mut()
+        .find(|s| s.player_index == player_index && s.status != SeatStatus::Left)
+        .ok_or("That player is not at the table.")?
+        .status = SeatStatus::Left;
+    }
+    let seat = self
+      .inactive_seats
+      .iter_mut()
+      .find(|s| s.player_index == player_index)
+      .unwrap();
+    let cash_out = seat.wallet;
+    seat.wallet = 0;
+    Ok(cash_out)
+  }
+
+  pub fn sit_out(&mut self, player_index: u8) -> Result<(), &'static str> {
+    self.check_between_hands()?;
+    self.deactivate_seat(player_index, SeatStatus::SittingOut)
+  }
+
+  pub fn sit_in(&mut self, player_index: u8) -> Result<(), &'static str> {
+    self.check_between_hands()?;
+    let seat = self
+      .inactive_seats
+      .iter()
+      .find(|s| s.player_index == player_index && s.status == SeatStatus::SittingOut)
+      .ok_or("That player is not sitting out.")?;
+    if seat.wallet < self.blind {
+      return Err("The player needs to rebuy before sitting in.");
+    }
+    let seat = self.take_inactive_seat(player_index).unwrap();
+    self.activate_seat(seat);
+    Ok(())
+  }
+
+  pub fn rebuy(&mut self, player_index: u8, amount: u32) -> Result<(), &'static str> {
+    self.check_between_hands()?;
+    if let Some(seat) = self.active_seats.iter_mut().find(|s| s.player_index == player_index) {
+      seat.wallet += amount;
+      return Ok(());
+    }
+    let seat = self
+      .inactive_seats
+      .iter_mut()
+      .find(|s| s.player_index == player_index)
+      .ok_or("There is no player with that index.")?;
+    match seat.status {
+      SeatStatus::Left => Err("That player has left the table."),
+      SeatStatus::Eliminated if seat.wallet + amount >= self.blind => {
+        let mut seat = self.take_inactive_seat(player_index).unwrap();
+        seat.wallet += amount;
+        self.activate_seat(seat);
+        Ok(())
+      }
+      _ => {
+        seat.wallet += amount;
+        Ok(())
+      }
+    }
+  }
+
+  pub fn set_muck_losing_hands(&mut self, player_index: u8, muck_losing_hands: bool) -> Result<(), &'static str> {
+    self
+      .active_seats
+      .iter_mut()
+      .chain(self.inactive_seats.iter_mut())
+      .find(|s| s.player_index == player_index)
+      .ok_or("There is no player with that index.")?
+      .muck_losing_hands = muck_losing_hands;
+    Ok(())
   }
 
   fn pick_available_card(&mut self) -> Card {
-    let mut rng = thread_rng();
     let mut available_cards = self.available_cards.get_cards();
-    let index = rng.gen_range(0..available_cards.len());
+    let index = self.rng.gen_range(0..available_cards.len());
     let card = available_cards.remove(index);
     self.available_cards.remove_card(card);
     card
@@ -101,13 +531,79 @@ impl Game {
 
   pub fn action_current_player(&mut self, action: BettingAction) -> Result<(), &'static str> {
     let seat = self.get_current_seat();
-    if seat.is_none() {
+    let Some(player_index) = seat.map(|s| s.player_index) else {
       return Err("This is not the right time to bet.");
+    };
+    self.action_log.push(LoggedAction {
+      player_index,
+      action,
+      snapshot: self.save_snapshot(),
+    });
+    if let Some(time_remaining) = self.get_time_remaining() {
+      let player_index = self.betting_round.get_current_player_index();
+      self.active_seats[player_index as usize].time_bank = time_remaining.time_bank;
     }
     self.bet_for_current_player(action);
+    self.start_turn();
     Ok(())
   }
 
+  pub fn get_hand_actions(&self) -> Vec<(u8, BettingAction)> {
+    self.action_log.iter().map(|a| (a.player_index, a.action)).collect()
+  }
+
+  pub fn undo_last_action(&mut self) -> Result<(), &'static str> {
+    if self.action_log.is_empty() {
+      return Err("There are no actions to undo.");
+    }
+    self.rewind_action_log(self.action_log.len() - 1);
+    Ok(())
+  }
+
+  pub fn rewind_to_street(&mut self, phase: Phase) -> Result<(), &'static str> {
+    let index = self
+      .action_log
+      .iter()
+      .position(|a| a.snapshot.get_phase() == phase)
+      .ok_or("There are no actions on that street to rewind.")?;
+    self.rewind_action_log(index);
+    Ok(())
+  }
+
+  fn rewind_action_log(&mut self, index: usize) {
+    let mut action_log = std::mem::take(&mut self.action_log);
+    let logged_action = action_log.drain(index..).next().unwrap();
+    *self = Game::create_from_snapshot_with_variant(logged_action.snapshot, self.variant.clone(), self.clock.clone());
+    self.action_log = action_log;
+  }
+
+  fn get_time_remaining(&self) -> Option<TimeRemaining> {
+    let time_limit = self.time_limit?;
+    let seat = self.get_current_seat()?;
+    let elapsed = self.get_turn_time_used();
+    Some(TimeRemaining {
+      action_time: time_limit.action_time.saturating_sub(elapsed),
+      time_bank: seat
+        .time_bank
+        .saturating_sub(elapsed.saturating_sub(time_limit.action_time)),
+    })
+  }
+
+  pub fn action_current_player_if_timed_out(&mut self) -> Option<BettingAction> {
+    let time_remaining = self.get_time_remaining()?;
+    if !time_remaining.action_time.is_zero() || !time_remaining.time_bank.is_zero() {
+      return None;
+    }
+    let player_index = self.betting_round.get_current_player_index();
+    let action = if self.betting_round.get_player_money_to_call(player_index) == 0 {
+      BettingAction::Call
+    } else {
+      BettingAction::Fold
+    };
+    self.action_current_player(action).unwrap();
+    Some(action)
+  }
+
   fn bet_for_current_player(&mut self, action: BettingAction) {
     let player_index = self.betting_round.get_current_player_index();
     let seat = &self.active_seats[player_index as usize];
@@ -139,57 +635,225 @@ impl Game {
   }
 
   fn init_round(&mut self) {
-    self.available_cards = Deck::full_deck();
+    self.available_cards = self.variant.get_deck();
     self.table = Deck::new();
     for i in 0..self.active_seats.len() {
-      self.active_seats[i].hand = Deck::new()
+      self.active_seats[i].hand = Deck::new();
+      self.active_seats[i].visible_cards = Deck::new();
     }
+    self.dealt_streets = 0;
+    self.showdown = vec![];
+    self.runouts = vec![];
+    self.all_in_equity = vec![];
+    self.hand_rake = 0;
+
+    let prev_dealer_seat = self.active_seats[self.dealer_index as usize].player_index;
+    let (eliminated_seats, active_seats): (Vec<Seat>, Vec<Seat>) =
+      self.active_seats.drain(..).partition(|s| s.wallet < self.blind);
+    self.active_seats = active_seats;
+    self.inactive_seats.extend(eliminated_seats.into_iter().map(|s| Seat {
+      status: SeatStatus::Eliminated,
+      ..s
+    }));
 
-    let mut new_dealer_player_index = None;
-    let mut invalid_player_indexes: Vec<u8> = vec![];
-    for i in 1..self.active_seats.len() {
-      let seat = &self.active_seats[(self.dealer_index as usize + i) % self.active_seats.len()];
-      if seat.wallet < self.blind {
-        invalid_player_indexes.push(seat.player_index);
-      } else if new_dealer_player_index.is_none() {
-        new_dealer_player_index = Some(seat.player_index);
+    let num_active_players = self.active_seats.len() as u8;
+    if num_active_players < 2 {
+      panic!("We do not have enough players.");
+    }
+
+    self.betting_round = BettingRound::create_for_players(num_active_players);
+    match (self.variant.get_forced_bets(), self.blind_positions) {
+      (ForcedBets::Blinds, Some(prev_positions)) => self.post_blinds_after(prev_positions),
+      (ForcedBets::Blinds, None) => {
+        self.move_dealer_after(prev_dealer_seat);
+        let small_blind_index = if num_active_players == 2 {
+          self.dealer_index
+        } else {
+          (self.dealer_index + 1) % num_active_players
+        };
+        let big_blind_index = (small_blind_index + 1) % num_active_players;
+        self.post_blinds(small_blind_index, big_blind_index);
+      }
+      (ForcedBets::AntesAndBringIn, _) => {
+        self.move_dealer_after(prev_dealer_seat);
+        let ante = self.blind / 10;
+        for i in 0..self.active_seats.len() {
+          self.betting_round.post_ante(i as u8, ante);
+          self.active_seats[i].wallet -= ante;
+        }
       }
     }
+  }
+
+  fn move_dealer_after(&mut self, prev_dealer_seat: u8) {
+    self.dead_button = None;
+    self.dealer_index = self.get_next_active_index_after(prev_dealer_seat);
+  }
 
+  fn get_next_active_index_after(&self, player_index: u8) -> u8 {
     self
       .active_seats
-      .retain(|s| !invalid_player_indexes.contains(&s.player_index));
+      .iter()
+      .position(|s| s.player_index > player_index)
+      .unwrap_or(0) as u8
+  }
+
+  fn get_active_index(&self, player_index: u8) -> Option<u8> {
+    self
+      .active_seats
+      .iter()
+      .position(|s| s.player_index == player_index)
+      .map(|idx| idx as u8)
+  }
 
+  // The big blind always moves on to the next player, and the small blind and button follow it into the seats the
+  // previous blinds sat in, even if those players have since left.
+  fn post_blinds_after(&mut self, prev_positions: BlindPositions) {
+    let big_blind_index = self.get_next_active_index_after(prev_positions.big_blind);
+    let big_blind_seat = self.active_seats[big_blind_index as usize].player_index;
     let num_active_players = self.active_seats.len() as u8;
-    if num_active_players < 2 {
-      panic!("We do not have enough players.");
+
+    for seat in self.inactive_seats.iter_mut() {
+      if seat.status != SeatStatus::SittingOut {
+        continue;
+      }
+      let is_passed_by_big_blind = if prev_positions.big_blind < big_blind_seat {
+        seat.player_index > prev_positions.big_blind && seat.player_index < big_blind_seat
+      } else {
+        seat.player_index > prev_positions.big_blind || seat.player_index < big_blind_seat
+      };
+      if is_passed_by_big_blind {
+        seat.missed_big_blind = true;
+        seat.missed_small_blind = true;
+      } else if seat.player_index == prev_positions.big_blind {
+        seat.missed_small_blind = true;
+      }
+    }
+
+    if num_active_players == 2 {
+      self.dealer_index = (big_blind_index + 1) % num_active_players;
+      self.dead_button = None;
+      self.post_blinds(self.dealer_index, big_blind_index);
+      return;
     }
 
-    self.dealer_index = self
+    let small_blind_index = self.get_active_index(prev_positions.big_blind);
+    match self.get_active_index(prev_positions.small_blind) {
+      Some(dealer_index) => {
+        self.dealer_index = dealer_index;
+        self.dead_button = None;
+      }
+      None => {
+        self.dealer_index =
+          (self.get_next_active_index_after(prev_positions.small_blind) + num_active_players - 1) % num_active_players;
+        self.dead_button = Some(prev_positions.small_blind);
+      }
+    }
+    self.post_blinds_for_seats(small_blind_index, prev_positions.big_blind, big_blind_index);
+  }
+
+  fn post_blinds(&mut self, small_blind_index: u8, big_blind_index: u8) {
+    let small_blind_seat = self.active_seats[small_blind_index as usize].player_index;
+    self.post_blinds_for_seats(Some(small_blind_index), small_blind_seat, big_blind_index);
+  }
+
+  fn post_blinds_for_seats(&mut self, small_blind_index: Option<u8>, small_blind_seat: u8, big_blind_index: u8) {
+    if let Some(idx) = small_blind_index {
+      self.post_blind(idx, self.blind / 2);
+    }
+    self.post_blind(big_blind_index, self.blind);
+
+    for idx in 0..self.active_seats.len() as u8 {
+      let seat = &self.active_seats[idx as usize];
+      let is_posting_blind = Some(idx) == small_blind_index || idx == big_blind_index;
+      if !is_posting_blind && seat.missed_big_blind {
+        self.post_blind(idx, self.blind);
+      }
+      if !is_posting_blind && self.active_seats[idx as usize].missed_small_blind {
+        let dead_blind = (self.blind / 2).min(self.active_seats[idx as usize].wallet);
+        self.betting_round.post_ante(idx, dead_blind);
+        self.active_seats[idx as usize].wallet -= dead_blind;
+      }
+      self.active_seats[idx as usize].missed_small_blind = false;
+      self.active_seats[idx as usize].missed_big_blind = false;
+    }
+
+    self.blind_positions = Some(BlindPositions {
+      small_blind: small_blind_seat,
+      big_blind: self.active_seats[big_blind_index as usize].player_index,
+    });
+    self.betting_round.set_new_start_position(big_blind_index + 1);
+  }
+
+  fn post_blind(&mut self, active_index: u8, amount: u32) {
+    self.betting_round.set_new_start_position(active_index);
+    let money_to_call = self.betting_round.get_player_money_to_call(active_index);
+    if amount > money_to_call {
+      self.bet_for_current_player(BettingAction::Raise(amount - money_to_call));
+    } else {
+      self.bet_for_current_player(BettingAction::Call);
+    }
+  }
+
+  fn get_visible_cards_of_unfolded_players(&self) -> Vec<Option<Deck>> {
+    let unfolded_players = self.betting_round.get_unfolded_player_indexes();
+    self
       .active_seats
       .iter()
-      .position(|s| s.player_index == new_dealer_player_index.unwrap())
-      .unwrap() as u8;
+      .enumerate()
+      .map(|(idx, s)| unfolded_players.contains(&(idx as u8)).then_some(s.visible_cards))
+      .collect()
+  }
 
-    self.betting_round = BettingRound::create_for_players(num_active_players);
-    self.betting_round.set_new_start_position(self.dealer_index + 1);
+  fn start_betting_on_street(&mut self, street: &Street) {
+    let first_to_act = self
+      .variant
+      .get_first_to_act(street.phase, &self.get_visible_cards_of_unfolded_players());
+    let is_first_street = self.dealt_streets == 1;
 
-    self.bet_for_current_player(BettingAction::Raise(self.blind / 2));
-    self.bet_for_current_player(BettingAction::Raise(self.blind / 2));
-    self.betting_round.set_new_start_position(self.dealer_index + 3);
+    match (first_to_act, is_first_street) {
+      (Some(idx), true) => {
+        self.betting_round.set_new_start_position(idx as u8);
+        self.bet_for_current_player(BettingAction::Raise(self.blind / 4));
+      }
+      (Some(idx), false) => self.betting_round.set_new_start_position(idx as u8),
+      (None, false) => self.betting_round.set_new_start_position(self.dealer_index + 1),
+      (None, true) => {}
+    }
   }
 
-  fn deal_cards_to_players(&mut self) {
+  fn deal_cards_to_players(&mut self, num_cards: u8, is_face_up: bool) {
+    let unfolded_players = self.betting_round.get_unfolded_player_indexes();
     let num_active_seats = self.active_seats.len();
-    for _ in 0..2 {
+    for _ in 0..num_cards {
       for pnum in 0..num_active_seats {
         let idx = (self.dealer_index as usize + pnum) % num_active_seats;
+        if !unfolded_players.contains(&(idx as u8)) {
+          continue;
+        }
         let card = self.pick_available_card();
         self.active_seats[idx].hand.add_card(card);
+        if is_face_up {
+          self.active_seats[idx].visible_cards.add_card(card);
+        }
       }
     }
   }
 
+  fn deal_street(&mut self, street: &Street) {
+    let num_unfolded_players = self.betting_round.get_unfolded_player_indexes().len();
+    let cards_per_player = street.hole_cards + street.up_cards;
+    if cards_per_player as usize * num_unfolded_players > self.available_cards.get_cards().len() {
+      // When there are not enough cards left for every player, a shared card is dealt to the table instead.
+      self.deal_cards_to_table(cards_per_player);
+    } else {
+      self.deal_cards_to_players(street.hole_cards, false);
+      self.deal_cards_to_players(street.up_cards, true);
+    }
+    self.deal_cards_to_table(street.table_cards);
+    self.dealt_streets += 1;
+  }
+
   fn deal_cards_to_table(&mut self, num_cards: u8) {
     for _ in 0..num_cards {
       let card = self.pick_available_card();
@@ -197,42 +861,295 @@ impl Game {
     }
   }
 
-  fn finalize(&mut self) {
+  fn get_scores(&self) -> (Vec<u16>, Vec<Option<u16>>) {
     let active_indexes = self.betting_round.get_unfolded_player_indexes();
+    if active_indexes.len() < 2 {
+      return (vec![0; self.active_seats.len()], vec![None; self.active_seats.len()]);
+    }
 
-    let active_scores = self
+    let high_scores = self
       .active_seats
       .iter()
       .enumerate()
       .map(|(i, p)| {
         if active_indexes.contains(&(i as u8)) {
-          get_hand_score(&self.table, &p.hand)
+          self.variant.get_hand_score(&self.table, &p.hand)
         } else {
           0
         }
       })
       .collect::<Vec<u16>>();
 
-    let winning_score = active_scores.iter().max().unwrap();
-    let winning_indexes = self
+    let low_scores = self
       .active_seats
       .iter()
       .enumerate()
-      .filter(|(i, _)| active_scores[*i] == *winning_score)
-      .map(|(i, _)| i)
-      .collect::<Vec<usize>>();
+      .map(|(i, p)| {
+        if active_indexes.contains(&(i as u8)) {
+          self.variant.get_low_hand_score(&self.table, &p.hand)
+        } else {
+          None
+        }
+      })
+      .collect::<Vec<Option<u16>>>();
+
+    (high_scores, low_scores)
+  }
 
-    let pot_splits = self.betting_round.get_pot_split(winning_indexes);
+  // The last player to bet or raise on the final street shows first, otherwise the first player after the dealer does.
+  fn get_showdown_order(&self) -> Vec<u8> {
+    let unfolded_players = self.betting_round.get_unfolded_player_indexes();
+    let num_seats = self.active_seats.len() as u8;
+    let first_to_show = self
+      .betting_round
+      .get_last_aggressor_index()
+      .unwrap_or((self.dealer_index + 1) % num_seats);
+    (0..num_seats)
+      .map(|offset| (first_to_show + offset) % num_seats)
+      .filter(|idx| unfolded_players.contains(idx))
+      .collect()
+  }
+
+  fn get_showdown_hands(&self, high_scores: &[u16], pot_splits: &[u32]) -> Vec<ShowdownHand> {
+    self
+      .get_showdown_order()
+      .iter()
+      .map(|&idx| ShowdownHand {
+        player_index: self.active_seats[idx as usize].player_index,
+        hand: self.active_seats[idx as usize].hand,
+        score: high_scores[idx as usize],
+        winnings: pot_splits[idx as usize],
+        is_mucked: false,
+      })
+      .collect()
+  }
+
+  fn reveal_hand(&mut self, active_index: u8) {
+    let seat = &mut self.active_seats[active_index as usize];
+    seat.visible_cards = seat.hand;
+  }
+
+  // Hands that were tabled when going all in can no longer be mucked, and the first hand to show is always tabled.
+  fn show_hands(&mut self, high_scores: &[u16], pot_splits: &[u32]) {
+    let is_tabled = !self.all_in_equity.is_empty();
+    let showdown_order = self.get_showdown_order();
+    self.showdown = self.get_showdown_hands(high_scores, pot_splits);
+    for (position, (showdown_hand, idx)) in self.showdown.iter_mut().zip(showdown_order).enumerate() {
+      let seat = &mut self.active_seats[idx as usize];
+      if !is_tabled && position > 0 && showdown_hand.winnings == 0 && seat.muck_losing_hands {
+        showdown_hand.hand = Deck::new();
+        showdown_hand.score = 0;
+        showdown_hand.is_mucked = true;
+      } else {
+        seat.visible_cards = seat.hand;
+      }
+    }
+  }
+
+  fn pay_out(&mut self, high_scores: &[u16], pot_splits: &[u32]) {
+    if self.betting_round.get_unfolded_player_indexes().len() > 1 {
+      self.show_hands(high_scores, pot_splits);
+    }
     for (idx, &split) in pot_splits.iter().enumerate() {
       self.active_seats[idx].wallet += split;
     }
   }
 
-  pub fn get_state(&self, player_index: Option<u8>) -> GameState {
+  // A hand that goes to showdown always sees a flop, even when the board is only run out after the betting is over.
+  fn will_see_flop(&self) -> bool {
+    self.dealt_streets >= 2 || self.betting_round.get_unfolded_player_indexes().len() > 1
+  }
+
+  fn take_rake(&mut self) {
+    let Some(rake) = self.rake else {
+      return;
+    };
+    if rake.no_flop_no_drop && !self.will_see_flop() {
+      return;
+    }
+    let amount = ((self.betting_round.get_called_pot() as f32 * rake.percentage / 100.0) as u32).min(rake.cap);
+    self.betting_round.set_rake(amount);
+    self.hand_rake = amount;
+    self.total_rake += amount;
+  }
+
+  fn finalize(&mut self) {
+    self.take_rake();
+    let (high_scores, low_scores) = self.get_scores();
+    let pot_splits = self.betting_round.get_pot_split(&high_scores, &low_scores);
+    self.pay_out(&high_scores, &pot_splits);
+  }
+
+  fn get_dealt_cards(&self) -> DealtCards {
+    DealtCards {
+      available_cards: self.available_cards,
+      table: self.table,
+      hands: self.active_seats.iter().map(|s| s.hand).collect(),
+      visible_cards: self.active_seats.iter().map(|s| s.visible_cards).collect(),
+      dealt_streets: self.dealt_streets,
+    }
+  }
+
+  fn set_dealt_cards(&mut self, dealt_cards: &DealtCards) {
+    self.available_cards = dealt_cards.available_cards;
+    self.table = dealt_cards.table;
+    for (idx, seat) in self.active_seats.iter_mut().enumerate() {
+      seat.hand = dealt_cards.hands[idx];
+      seat.visible_cards = dealt_cards.visible_cards[idx];
+    }
+    self.dealt_streets = dealt_cards.dealt_streets;
+  }
+
+  fn run_out(&mut self, streets: &[Street]) {
+    for street in &streets[self.dealt_streets..] {
+      self.deal_street(street);
+    }
+  }
+
+  // The samples are drawn from their own generator so that working out the equity does not change what is dealt next.
+  fn calculate_all_in_equity(&mut self, streets: &[Street]) -> Vec<PlayerEquity> {
+    let unfolded_players = self.betting_round.get_unfolded_player_indexes();
+    let dealt_cards = self.get_dealt_cards();
+    let mut wins = vec![0.0; self.active_seats.len()];
+    let equity_rng = ChaCha12Rng::from_rng(self.rng.clone()).unwrap();
+    let game_rng = std::mem::replace(&mut self.rng, equity_rng);
+
+    for _ in 0..ALL_IN_EQUITY_SAMPLES {
+      self.run_out(streets);
+      let (high_scores, low_scores) = self.get_scores();
+      let best_low = low_scores.iter().flatten().max();
+      let high_share = if best_low.is_some() { 0.5 } else { 1.0 };
+
+      let best_high = unfolded_players.iter().map(|&idx| high_scores[idx as usize]).max();
+      let high_winners = unfolded_players
+        .iter()
+        .filter(|&&idx| Some(high_scores[idx as usize]) == best_high)
+        .collect::<Vec<_>>();
+      for &&idx in &high_winners {
+        wins[idx as usize] += high_share / high_winners.len() as f32;
+      }
+
+      let low_winners = unfolded_players
+        .iter()
+        .filter(|&&idx| best_low.is_some() && low_scores[idx as usize].as_ref() == best_low)
+        .collect::<Vec<_>>();
+      for &&idx in &low_winners {
+        wins[idx as usize] += 0.5 / low_winners.len() as f32;
+      }
+
+      self.set_dealt_cards(&dealt_cards);
+    }
+    self.rng = game_rng;
+
+    unfolded_players
+      .iter()
+      .map(|&idx| PlayerEquity {
+        player_index: self.active_seats[idx as usize].player_index,
+        equity: wins[idx as usize] / ALL_IN_EQUITY_SAMPLES as f32,
+      })
+      .collect()
+  }
+
+  // Each run pays out the whole pot, and the final amounts are averaged over all runs. Chips that do not divide evenly
+  // go to the biggest winner. Later runs are dealt from the cards left over by earlier ones, so the board is only run
+  // as many times as the remaining deck allows.
+  fn run_it_multiple_times(&mut self, streets: &[Street]) {
+    self.take_rake();
+    let dealt_cards = self.get_dealt_cards();
+    let mut available_cards = self.available_cards;
+    let mut cards_per_run = 0;
+    let mut total_splits = vec![0; self.active_seats.len()];
+    let mut high_scores = vec![];
+
+    for _ in 0..self.run_it_times {
+      if available_cards.get_cards().len() < cards_per_run {
+        break;
+      }
+      self.set_dealt_cards(&dealt_cards);
+      self.available_cards = available_cards;
+      self.run_out(streets);
+      cards_per_run = available_cards.get_cards().len() - self.available_cards.get_cards().len();
+      available_cards = self.available_cards;
+      let (run_high_scores, low_scores) = self.get_scores();
+      let pot_splits = self.betting_round.get_pot_split(&run_high_scores, &low_scores);
+      self.runouts.push(Runout {
+        table: self.table,
+        hands: self.get_showdown_hands(&run_high_scores, &pot_splits),
+      });
+      for (idx, split) in pot_splits.iter().enumerate() {
+        total_splits[idx] += split;
+      }
+      high_scores = run_high_scores;
+    }
+
+    let runs = self.runouts.len() as u32;
+    let mut pot_splits = total_splits.iter().map(|split| split / runs).collect::<Vec<_>>();
+    let remainder = total_splits.iter().sum::<u32>() / runs - pot_splits.iter().sum::<u32>();
+    let biggest_winner = (0..total_splits.len())
+      .rev()
+      .max_by_key(|&idx| total_splits[idx])
+      .unwrap();
+    pot_splits[biggest_winner] += remainder;
+    self.pay_out(&high_scores, &pot_splits);
+  }
+
+  pub fn get_runouts(&self) -> &[Runout] {
+    &self.runouts
+  }
+
+  pub fn get_showdown(&self) -> &[ShowdownHand] {
+    &self.showdown
+  }
+
+  fn get_missed_blinds(&self, seat: &Seat) -> u32 {
+    let big_blind = if seat.missed_big_blind { self.blind } else { 0 };
+    let small_blind = if seat.missed_small_blind { self.blind / 2 } else { 0 };
+    big_blind + small_blind
+  }
+
+  fn get_player_states(&self, viewer: Viewer) -> Vec<PlayerState> {
     let player_bets = self.betting_round.get_player_bets();
     let unfolded_players = self.betting_round.get_unfolded_player_indexes();
 
-    let active_seat_index = if let Some(player_index) = player_index {
+    let mut player_states = self
+      .active_seats
+      .iter()
+      .enumerate()
+      .map(|(idx, s)| PlayerState {
+        player_index: s.player_index,
+        is_eliminated: false,
+        is_sitting_out: false,
+        has_left: false,
+        missed_blinds: self.get_missed_blinds(s),
+        wallet: s.wallet,
+        money_on_table: player_bets[idx],
+        is_folded: !unfolded_players.contains(&(idx as u8)),
+        visible_cards: s.visible_cards,
+        hole_cards: viewer.can_see_hand_of(s.player_index).then_some(s.hand),
+      })
+      .chain(self.inactive_seats.iter().map(|s| PlayerState {
+        player_index: s.player_index,
+        is_eliminated: s.status == SeatStatus::Eliminated,
+        is_sitting_out: s.status == SeatStatus::SittingOut,
+        has_left: s.status == SeatStatus::Left,
+        missed_blinds: self.get_missed_blinds(s),
+        wallet: s.wallet,
+        money_on_table: 0,
+        is_folded: true,
+        visible_cards: Deck::new(),
+        hole_cards: None,
+      }))
+      .collect::<Vec<_>>();
+    player_states.sort_by_key(|p| p.player_index);
+    player_states
+  }
+
+  pub fn get_state(&self, player_index: Option<u8>) -> GameState {
+    self.get_view(player_index.map_or(Viewer::Spectator, Viewer::Player))
+  }
+
+  pub fn get_view(&self, viewer: Viewer) -> GameState {
+    let active_seat_index = if let Viewer::Player(player_index) = viewer {
       self.active_seats.iter().position(|p| p.player_index == player_index)
     } else {
       None
@@ -243,18 +1160,17 @@ impl Game {
       total_pot: self.betting_round.get_pot(),
       table: self.table,
       phase: self.phase,
-      players: self
-        .active_seats
-        .iter()
-        .enumerate()
-        .map(|(idx, s)| PlayerState {
-          wallet: s.wallet,
-          money_on_table: player_bets[idx],
-          is_folded: !unfolded_players.contains(&(idx as u8)),
-        })
-        .collect(),
+      players: self.get_player_states(viewer),
       current_player_index: self.get_current_seat().map(|cs| cs.player_index),
-      dealer_index: self.active_seats[self.dealer_index as usize].player_index,
+      dealer_index: self
+        .dead_button
+        .or_else(|| {
+          self
+            .active_seats
+            .get(self.dealer_index as usize)
+            .map(|s| s.player_index)
+        })
+        .unwrap_or_default(),
       hand: if let Some(s) = player_seat { s.hand } else { Deck::new() },
       wallet: if let Some(s) = player_seat { s.wallet } else { 0 },
       value_to_call: if let Some(idx) = active_seat_index {
@@ -262,6 +1178,10 @@ impl Game {
       } else {
         0
       },
+      time_remaining: self.get_time_remaining(),
+      all_in_equity: self.all_in_equity.clone(),
+      remaining_deck: (viewer == Viewer::Admin).then_some(self.available_cards),
+      variant: self.variant.clone(),
     }
   }
 }
@@ -274,58 +1194,56 @@ impl Iterator for Game {
       return Some(self.phase);
     }
 
+    let streets = self.variant.get_streets();
     match self.phase {
       Phase::Init => {
-        self.init_round();
-        self.deal_cards_to_players();
-        self.phase = Phase::PreFlop;
-      }
-      Phase::PreFlop => {
-        if self.betting_round.get_num_players_able_to_bets() > 1 {
-          self.betting_round.reset_for_next_phase();
-          self.deal_cards_to_table(3);
-
-          self.betting_round.set_new_start_position(self.dealer_index + 1);
-          self.phase = Phase::Flop;
-        } else {
-          self.phase = Phase::Showdown;
-        }
-      }
-      Phase::Flop => {
-        if self.betting_round.get_num_players_able_to_bets() > 1 {
-          self.betting_round.reset_for_next_phase();
-          self.deal_cards_to_table(1);
-          self.phase = Phase::Turn;
-        } else {
-          self.phase = Phase::Showdown;
-        }
-      }
-      Phase::Turn => {
-        if self.betting_round.get_num_players_able_to_bets() > 1 {
-          self.betting_round.reset_for_next_phase();
-          self.deal_cards_to_table(1);
-          self.phase = Phase::River;
-        } else {
-          self.phase = Phase::Showdown;
+        if self.active_seats.iter().filter(|s| s.wallet >= self.blind).count() < 2 {
+          return None;
         }
-      }
-      Phase::River => {
-        self.phase = Phase::Showdown;
+        self.init_round();
+        self.deal_street(&streets[0]);
+        self.start_betting_on_street(&streets[0]);
+        self.phase = streets[0].phase;
       }
       Phase::Showdown => {
-        let cards_on_table = self.table.get_cards().len() as u8;
-        if cards_on_table < 5 {
-          self.deal_cards_to_table(5 - cards_on_table);
+        if self.run_it_times > 1 && !self.all_in_equity.is_empty() {
+          self.run_it_multiple_times(&streets);
+        } else {
+          if self.betting_round.get_unfolded_player_indexes().len() > 1 {
+            self.run_out(&streets);
+          }
+          self.finalize();
         }
-        self.finalize();
+        self.phase = Phase::Init;
+        self.action_log = vec![];
         let valid_players = self.active_seats.iter().filter(|p| p.wallet >= self.blind).count();
         if valid_players < 2 {
           return None;
         }
-
-        self.phase = Phase::Init;
+      }
+      _ => {
+        let street_index = streets.iter().position(|s| s.phase == self.phase).unwrap();
+        match streets.get(street_index + 1) {
+          Some(next_street) if self.betting_round.get_num_players_able_to_bets() > 1 => {
+            self.betting_round.reset_for_next_phase();
+            self.deal_street(next_street);
+            self.start_betting_on_street(next_street);
+            self.phase = next_street.phase;
+          }
+          Some(_) if self.betting_round.get_unfolded_player_indexes().len() > 1 => {
+            for idx in self.betting_round.get_unfolded_player_indexes() {
+              self.reveal_hand(idx);
+            }
+            self.all_in_equity = self.calculate_all_in_equity(&streets);
+            self.phase = Phase::Showdown;
+          }
+          _ => {
+            self.phase = Phase::Showdown;
+          }
+        }
       }
     };
+    self.start_turn();
     Some(self.phase)
   }
 }
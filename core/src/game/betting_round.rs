@@ -6,6 +6,8 @@ pub enum BettingActionWithAmount {
   AllIn(u32),
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct PlayerBet {
   money_in_pot: u32,
   money_on_table: u32,
@@ -19,12 +21,16 @@ impl PlayerBet {
   }
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BettingRound {
   current_bet: u32,
   player_bets: Vec<PlayerBet>,
   current_player_index: u8,
   final_player_index: u8,
   is_complete: bool,
+  last_aggressor_index: Option<u8>,
+  rake: u32,
 }
 
 impl BettingRound {
@@ -40,20 +46,27 @@ impl BettingRound {
         })
         .collect(),
       current_player_index: 0,
-      final_player_index: players - 1,
+      final_player_index: players.saturating_sub(1),
       is_complete: false,
+      last_aggressor_index: None,
+      rake: 0,
     }
   }
 
   pub fn reset_for_next_phase(&mut self) {
     self.current_bet = 0;
     self.is_complete = false;
+    self.last_aggressor_index = None;
     for p in &mut self.player_bets {
       p.money_on_table = 0;
     }
     self.set_new_start_position(self.current_player_index + 1);
   }
 
+  pub fn post_ante(&mut self, player_index: u8, amount: u32) {
+    self.player_bets[player_index as usize].money_in_pot += amount;
+  }
+
   fn get_prev_active_index(&self, start_index: u8) -> u8 {
     let total_players = self.player_bets.len() as u8;
     let mut prev_index = start_index;
@@ -108,6 +121,7 @@ impl BettingRound {
         player.money_in_pot += value_to_subtract;
         self.current_bet = player.money_on_table;
         self.final_player_index = previous_player_index;
+        self.last_aggressor_index = Some(self.current_player_index);
       }
       BettingActionWithAmount::AllIn(remaining_amount) => {
         value_to_subtract = remaining_amount;
@@ -117,6 +131,7 @@ impl BettingRound {
         if player.money_on_table > self.current_bet {
           self.current_bet = player.money_on_table;
           self.final_player_index = previous_player_index;
+          self.last_aggressor_index = Some(self.current_player_index);
         }
       }
     };
@@ -142,6 +157,14 @@ impl BettingRound {
     Ok(value_to_subtract)
   }
 
+  pub fn is_valid_for_players(&self, players: usize) -> bool {
+    let is_valid_index = |idx: u8| players == 0 || (idx as usize) < players;
+    self.player_bets.len() == players
+      && is_valid_index(self.current_player_index)
+      && is_valid_index(self.final_player_index)
+      && self.last_aggressor_index.is_none_or(is_valid_index)
+  }
+
   pub fn is_complete(&self) -> bool {
     self.is_complete
   }
@@ -150,6 +173,10 @@ impl BettingRound {
     self.current_player_index
   }
 
+  pub fn get_last_aggressor_index(&self) -> Option<u8> {
+    self.last_aggressor_index
+  }
+
   pub fn get_player_bets(&self) -> Vec<u32> {
     self.player_bets.iter().map(|p| p.money_on_table).collect()
   }
@@ -176,7 +203,37 @@ impl BettingRound {
     self.player_bets.iter().map(|p| p.money_in_pot).sum()
   }
 
-  pub fn get_pot_split(&self, winning_indexes: Vec<usize>) -> Vec<u32> {
+  pub fn get_called_pot(&self) -> u32 {
+    let mut money_in_pot = self.player_bets.iter().map(|p| p.money_in_pot).collect::<Vec<u32>>();
+    money_in_pot.sort_unstable_by(|a, b| b.cmp(a));
+    let called_amount = money_in_pot.get(1).copied().unwrap_or(0);
+    money_in_pot.iter().map(|&v| v.min(called_amount)).sum()
+  }
+
+  pub fn set_rake(&mut self, rake: u32) {
+    self.rake = rake;
+  }
+
+  fn split_between(pot_size: u32, winning_indexes: &[usize], pot_split: &mut [u32]) {
+    let num_splits = winning_indexes.len() as u32;
+    for (i, &idx) in winning_indexes.iter().enumerate() {
+      pot_split[idx] += pot_size / num_splits;
+      if i == 0 {
+        pot_split[idx] += pot_size % num_splits;
+      }
+    }
+  }
+
+  fn get_best_indexes<T: Ord + Copy>(scores: &[T], eligible_indexes: &[usize]) -> Vec<usize> {
+    let best_score = eligible_indexes.iter().map(|&idx| scores[idx]).max();
+    eligible_indexes
+      .iter()
+      .filter(|&&idx| Some(scores[idx]) == best_score)
+      .copied()
+      .collect()
+  }
+
+  pub fn get_pot_split(&self, high_scores: &[u16], low_scores: &[Option<u16>]) -> Vec<u32> {
     let mut pot_split = vec![0; self.player_bets.len()];
 
     let player_money_in_pot = self.player_bets.iter().map(|p| p.money_in_pot).collect::<Vec<u32>>();
@@ -185,24 +242,46 @@ impl BettingRound {
     split_amounts.sort();
     split_amounts.dedup();
 
+    let mut rake_remaining = self.rake;
     let mut prev_split_value = 0;
+    let mut prev_indexes_eligible_for_split = vec![];
     for split in split_amounts {
-      let pot_size = player_money_in_pot
+      let full_pot_size = player_money_in_pot
         .iter()
         .filter(|pb| **pb >= split)
         .collect::<Vec<_>>()
         .len() as u32
         * (split - prev_split_value);
+      prev_split_value = split;
+      let rake = rake_remaining.min(full_pot_size);
+      rake_remaining -= rake;
+      let pot_size = full_pot_size - rake;
 
-      let indexes_eligible_for_split = winning_indexes
+      let mut indexes_eligible_for_split = self
+        .player_bets
         .iter()
-        .filter(|&idx| player_money_in_pot[*idx] >= split)
+        .enumerate()
+        .filter(|(_, p)| !p.is_folded && p.money_in_pot >= split)
+        .map(|(idx, _)| idx)
         .collect::<Vec<_>>();
-      let num_splits = indexes_eligible_for_split.len() as u32;
-      for &idx in indexes_eligible_for_split {
-        pot_split[idx] += pot_size / num_splits;
+      // Money nobody left in the hand put in, such as a dead blind from a player who folded, goes to the highest pot.
+      if indexes_eligible_for_split.is_empty() {
+        indexes_eligible_for_split = prev_indexes_eligible_for_split.clone();
       }
-      prev_split_value = split;
+      if indexes_eligible_for_split.is_empty() {
+        continue;
+      }
+
+      let high_indexes = Self::get_best_indexes(high_scores, &indexes_eligible_for_split);
+      let low_indexes = Self::get_best_indexes(low_scores, &indexes_eligible_for_split);
+      if low_scores[low_indexes[0]].is_some() {
+        let low_pot_size = pot_size / 2;
+        Self::split_between(pot_size - low_pot_size, &high_indexes, &mut pot_split);
+        Self::split_between(low_pot_size, &low_indexes, &mut pot_split);
+      } else {
+        Self::split_between(pot_size, &high_indexes, &mut pot_split);
+      }
+      prev_indexes_eligible_for_split = indexes_eligible_for_split;
     }
 
     pot_split
@@ -3,11 +3,11 @@ use super::*;
 #[test]
 fn all_players_check() {
   let mut br = BettingRound::create_for_players(2);
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(true, br.is_complete);
+  assert!(br.is_complete);
   assert_eq!(0, br.current_bet);
 }
 
@@ -15,9 +15,9 @@ fn all_players_check() {
 fn calling_a_raise() {
   let mut br = BettingRound::create_for_players(2);
   br.action_current_player(BettingActionWithAmount::Raise(200)).unwrap();
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(true, br.is_complete);
+  assert!(br.is_complete);
   assert_eq!(200, br.current_bet);
 }
 
@@ -26,7 +26,7 @@ fn raising_extends_betting() {
   let mut br = BettingRound::create_for_players(2);
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
   br.action_current_player(BettingActionWithAmount::Raise(200)).unwrap();
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
 }
 
 #[test]
@@ -50,9 +50,9 @@ fn skip_players_who_have_folded() {
   br.action_current_player(BettingActionWithAmount::Raise(200)).unwrap();
   br.action_current_player(BettingActionWithAmount::Fold).unwrap();
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(true, br.is_complete);
+  assert!(br.is_complete);
 }
 
 #[test]
@@ -62,9 +62,9 @@ fn skip_players_who_have_gone_all_in() {
   br.action_current_player(BettingActionWithAmount::Raise(200)).unwrap();
   br.action_current_player(BettingActionWithAmount::AllIn(100)).unwrap();
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(true, br.is_complete);
+  assert!(br.is_complete);
 }
 
 #[test]
@@ -106,7 +106,7 @@ fn restarting_bets_resets_values() {
   br.reset_for_next_phase();
   assert_eq!(vec![0, 0], br.get_player_bets());
   assert_eq!(0, br.current_bet);
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
 }
 
 #[test]
@@ -115,7 +115,7 @@ fn restarting_bets_keeps_players_folded() {
   br.action_current_player(BettingActionWithAmount::Raise(200)).unwrap();
   br.action_current_player(BettingActionWithAmount::Fold).unwrap();
   br.reset_for_next_phase();
-  assert_eq!(true, br.player_bets[1].is_folded);
+  assert!(br.player_bets[1].is_folded);
 }
 
 #[test]
@@ -124,7 +124,7 @@ fn restarting_bets_keeps_players_all_in() {
   br.action_current_player(BettingActionWithAmount::Raise(200)).unwrap();
   br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
   br.reset_for_next_phase();
-  assert_eq!(true, br.player_bets[1].is_all_in);
+  assert!(br.player_bets[1].is_all_in);
 }
 
 #[test]
@@ -182,14 +182,14 @@ fn if_the_last_opposing_player_folds_it_should_complete() {
   let mut br = BettingRound::create_for_players(2);
   br.set_new_start_position(1);
   br.action_current_player(BettingActionWithAmount::Fold).unwrap();
-  assert_eq!(true, br.is_complete);
+  assert!(br.is_complete);
 }
 
 #[test]
 fn if_the_last_opposing_player_went_all_in_do_another_round_of_betting() {
   let mut br = BettingRound::create_for_players(2);
   br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
-  assert_eq!(false, br.is_complete);
+  assert!(!br.is_complete);
 }
 
 #[test]
@@ -227,7 +227,7 @@ fn should_split_pot_properly_with_all_in_player() {
   br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
   br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(vec![700, 300, 0], br.get_pot_split(vec![0, 1]));
+  assert_eq!(vec![700, 300, 0], br.get_pot_split(&[1, 1, 0], &[None, None, None]));
 }
 
 #[test]
@@ -236,5 +236,126 @@ fn should_split_pot_evenly_between_winners() {
   br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
   br.action_current_player(BettingActionWithAmount::Call).unwrap();
-  assert_eq!(vec![400, 400, 400], br.get_pot_split(vec![0, 1, 2]));
+  assert_eq!(vec![400, 400, 400], br.get_pot_split(&[1, 1, 1], &[None, None, None]));
+}
+
+#[test]
+fn should_give_side_pots_to_the_best_eligible_hand() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(vec![400, 600, 0], br.get_pot_split(&[2, 3, 1], &[None, None, None]));
+}
+
+#[test]
+fn should_not_give_folded_players_any_of_the_pot() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.action_current_player(BettingActionWithAmount::Fold).unwrap();
+  assert_eq!(vec![0, 800, 0], br.get_pot_split(&[1, 2, 3], &[None, None, None]));
+}
+
+#[test]
+fn should_give_the_odd_chip_to_the_first_winner() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(101)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.action_current_player(BettingActionWithAmount::Fold).unwrap();
+  br.player_bets[2].money_in_pot = 1;
+  assert_eq!(vec![102, 101, 0], br.get_pot_split(&[1, 1, 0], &[None, None, None]));
+}
+
+#[test]
+fn should_give_a_dead_blind_from_a_folded_player_to_the_winner() {
+  let mut br = BettingRound::create_for_players(3);
+  br.post_ante(2, 10);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.reset_for_next_phase();
+  br.set_new_start_position(2);
+  br.action_current_player(BettingActionWithAmount::Fold).unwrap();
+  br.set_rake(25);
+
+  let pot_split = br.get_pot_split(&[3, 2, 1], &[None, None, None]);
+  assert_eq!(br.get_pot() - 25, pot_split.iter().sum::<u32>());
+  assert_eq!(vec![1185, 0, 0], pot_split);
+}
+
+#[test]
+fn should_split_pot_between_high_and_low_hands() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(
+    vec![600, 0, 600],
+    br.get_pot_split(&[3, 2, 1], &[None, Some(10), Some(20)])
+  );
+}
+
+#[test]
+fn should_scoop_pot_with_the_high_hand_when_nobody_qualifies_for_low() {
+  let mut br = BettingRound::create_for_players(2);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(vec![800, 0], br.get_pot_split(&[3, 2], &[None, None]));
+}
+
+#[test]
+fn should_quarter_the_pot_when_low_hands_tie() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(
+    vec![900, 300, 0],
+    br.get_pot_split(&[3, 2, 1], &[Some(20), Some(20), None])
+  );
+}
+
+#[test]
+fn should_split_side_pots_between_high_and_low_hands() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(
+    vec![500, 300, 200],
+    br.get_pot_split(&[1, 3, 2], &[Some(20), Some(10), None])
+  );
+}
+
+#[test]
+fn should_take_the_rake_from_the_main_pot_first() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.set_rake(50);
+  assert_eq!(vec![675, 275, 0], br.get_pot_split(&[1, 1, 0], &[None, None, None]));
+}
+
+#[test]
+fn should_not_count_uncalled_money_in_the_called_pot() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Raise(400)).unwrap();
+  br.action_current_player(BettingActionWithAmount::AllIn(200)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Fold).unwrap();
+  assert_eq!(400, br.get_called_pot());
+}
+
+#[test]
+fn should_track_the_last_aggressor_on_each_street() {
+  let mut br = BettingRound::create_for_players(3);
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(None, br.get_last_aggressor_index());
+  br.action_current_player(BettingActionWithAmount::Raise(100)).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  br.action_current_player(BettingActionWithAmount::Call).unwrap();
+  assert_eq!(Some(1), br.get_last_aggressor_index());
+  br.reset_for_next_phase();
+  assert_eq!(None, br.get_last_aggressor_index());
 }
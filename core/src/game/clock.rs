@@ -0,0 +1,42 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Times are measured from whenever the clock started, so a game never needs to know the actual time.
+pub trait Clock: Send + Sync {
+  fn now(&self) -> Duration;
+}
+
+pub struct SystemClock {
+  started_at: Instant,
+}
+
+impl SystemClock {
+  pub fn create() -> SystemClock {
+    SystemClock {
+      started_at: Instant::now(),
+    }
+  }
+}
+
+impl Clock for SystemClock {
+  fn now(&self) -> Duration {
+    self.started_at.elapsed()
+  }
+}
+
+#[derive(Default)]
+pub struct ManualClock {
+  now: Mutex<Duration>,
+}
+
+impl ManualClock {
+  pub fn advance(&self, duration: Duration) {
+    *self.now.lock().unwrap() += duration;
+  }
+}
+
+impl Clock for ManualClock {
+  fn now(&self) -> Duration {
+    *self.now.lock().unwrap()
+  }
+}
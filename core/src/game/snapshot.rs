@@ -0,0 +1,183 @@
+use super::*;
+use crate::variant::get_variant_by_name;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameSnapshot {
+  phase: Phase,
+  available_cards: Deck,
+  table: Deck,
+  betting_round: BettingRound,
+  dealer_index: u8,
+  dead_button: Option<u8>,
+  blind_positions: Option<BlindPositions>,
+  blind: u32,
+  active_seats: Vec<Seat>,
+  inactive_seats: Vec<Seat>,
+  variant: String,
+  dealt_streets: usize,
+  time_limit: Option<TimeLimit>,
+  turn_time_used: Duration,
+  showdown: Vec<ShowdownHand>,
+  rng: ChaCha12Rng,
+  run_it_times: u8,
+  runouts: Vec<Runout>,
+  all_in_equity: Vec<PlayerEquity>,
+  rake: Option<Rake>,
+  hand_rake: u32,
+  total_rake: u32,
+}
+
+impl GameSnapshot {
+  pub(super) fn get_phase(&self) -> Phase {
+    self.phase
+  }
+
+  // Snapshots may have been edited by hand, so anything the game indexes into is checked before it is restored. Blinds
+  // and the dead button can belong to players who have since left, so they are checked against every seat.
+  fn validate(&self, variant: &Arc<dyn Variant>) -> Result<(), &'static str> {
+    let streets = variant.get_streets();
+    match self.phase {
+      Phase::Init | Phase::Showdown => {
+        if self.dealt_streets > streets.len() {
+          return Err("The snapshot has dealt more streets than the variant has.");
+        }
+      }
+      phase => match streets.iter().position(|s| s.phase == phase) {
+        Some(street_index) if street_index + 1 == self.dealt_streets => {}
+        Some(_) => return Err("The snapshot has not dealt the streets for its phase."),
+        None => return Err("The snapshot is in a phase the variant does not have."),
+      },
+    }
+    let num_active_seats = self.active_seats.len();
+    if num_active_seats > 0 && self.dealer_index as usize >= num_active_seats {
+      return Err("The snapshot does not have a valid dealer.");
+    }
+    if !self.betting_round.is_valid_for_players(num_active_seats) {
+      return Err("The snapshot betting round does not match the players.");
+    }
+    let is_seated = |player_index: u8| {
+      self
+        .active_seats
+        .iter()
+        .chain(self.inactive_seats.iter())
+        .any(|s| s.player_index == player_index)
+    };
+    let blinds_are_seated = self
+      .blind_positions
+      .is_none_or(|p| is_seated(p.small_blind) && is_seated(p.big_blind));
+    if !blinds_are_seated || !self.dead_button.is_none_or(is_seated) {
+      return Err("The snapshot has blinds or a button for players who are not at the table.");
+    }
+    if self.phase != Phase::Init {
+      self.validate_cards(&streets)?;
+    }
+    Ok(())
+  }
+
+  // Only checked during a hand, since the cards are all dealt again when the next hand starts.
+  fn validate_cards(&self, streets: &[Street]) -> Result<(), &'static str> {
+    let mut seen = Deck::new();
+    let dealt_decks = self.active_seats.iter().map(|s| &s.hand).chain([&self.table, &self.available_cards]);
+    for deck in dealt_decks {
+      for card in deck.get_cards() {
+        if seen.has_card(card) {
+          return Err("The snapshot has the same card in more than one place.");
+        }
+        seen.add_card(card);
+      }
+    }
+
+    // Mirrors deal_street, which deals to the table instead when there are not enough cards for every player.
+    let num_unfolded_players = self.betting_round.get_unfolded_player_indexes().len();
+    let mut num_cards_left = self.available_cards.get_cards().len();
+    for street in &streets[self.dealt_streets..] {
+      let cards_per_player = (street.hole_cards + street.up_cards) as usize;
+      let num_player_cards = if cards_per_player * num_unfolded_players > num_cards_left {
+        cards_per_player
+      } else {
+        cards_per_player * num_unfolded_players
+      };
+      num_cards_left = num_cards_left
+        .checked_sub(num_player_cards + street.table_cards as usize)
+        .ok_or("The snapshot does not have enough cards left to deal the rest of the hand.")?;
+    }
+    Ok(())
+  }
+}
+
+impl Game {
+  pub fn save_snapshot(&self) -> GameSnapshot {
+    GameSnapshot {
+      phase: self.phase,
+      available_cards: self.available_cards,
+      table: self.table,
+      betting_round: self.betting_round.clone(),
+      dealer_index: self.dealer_index,
+      dead_button: self.dead_button,
+      blind_positions: self.blind_positions,
+      blind: self.blind,
+      active_seats: self.active_seats.clone(),
+      inactive_seats: self.inactive_seats.clone(),
+      variant: self.variant.get_name().to_string(),
+      dealt_streets: self.dealt_streets,
+      time_limit: self.time_limit,
+      turn_time_used: self.get_turn_time_used(),
+      showdown: self.showdown.clone(),
+      rng: self.rng.clone(),
+      run_it_times: self.run_it_times,
+      runouts: self.runouts.clone(),
+      all_in_equity: self.all_in_equity.clone(),
+      rake: self.rake,
+      hand_rake: self.hand_rake,
+      total_rake: self.total_rake,
+    }
+  }
+
+  pub fn create_from_snapshot(snapshot: GameSnapshot) -> Result<Game, &'static str> {
+    let variant = get_variant_by_name(&snapshot.variant).ok_or("The snapshot is for an unknown variant.")?;
+    snapshot.validate(&variant)?;
+    Ok(Game::create_from_snapshot_with_variant(
+      snapshot,
+      variant,
+      Arc::new(SystemClock::create()),
+    ))
+  }
+
+  pub(super) fn create_from_snapshot_with_variant(
+    snapshot: GameSnapshot,
+    variant: Arc<dyn Variant>,
+    clock: Arc<dyn Clock>,
+  ) -> Game {
+    Game {
+      phase: snapshot.phase,
+      available_cards: snapshot.available_cards,
+      table: snapshot.table,
+      betting_round: snapshot.betting_round,
+      dealer_index: snapshot.dealer_index,
+      dead_button: snapshot.dead_button,
+      blind_positions: snapshot.blind_positions,
+      blind: snapshot.blind,
+      active_seats: snapshot.active_seats,
+      inactive_seats: snapshot.inactive_seats,
+      variant,
+      dealt_streets: snapshot.dealt_streets,
+      time_limit: snapshot.time_limit,
+      turn_started_at: clock.now(),
+      clock,
+      carried_turn_time: snapshot.turn_time_used,
+      showdown: snapshot.showdown,
+      rng: snapshot.rng,
+      run_it_times: snapshot.run_it_times,
+      runouts: snapshot.runouts,
+      all_in_equity: snapshot.all_in_equity,
+      rake: snapshot.rake,
+      hand_rake: snapshot.hand_rake,
+      total_rake: snapshot.total_rake,
+      action_log: vec![],
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests;
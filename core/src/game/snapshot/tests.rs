@@ -0,0 +1,219 @@
+use crate::game::*;
+use crate::variant::SevenCardStud;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn play_to_the_flop(game: &mut Game) {
+  game.next();
+  while game.phase != Phase::Flop {
+    game.action_current_player(BettingAction::Call).unwrap();
+    game.next();
+  }
+}
+
+fn play_to_showdown(game: &mut Game) {
+  while game.phase != Phase::Init {
+    if game.get_current_player_index().is_some() {
+      game.action_current_player(BettingAction::Call).unwrap();
+    }
+    game.next();
+  }
+}
+
+#[test]
+fn should_restore_the_game_where_it_was_saved() {
+  let mut game = Game::create(3, 1000);
+  game.set_seed(5);
+  play_to_the_flop(&mut game);
+  game.action_current_player(BettingAction::Raise(40)).unwrap();
+
+  let restored = Game::create_from_snapshot(game.save_snapshot()).unwrap();
+  let state = game.get_view(Viewer::Admin);
+  let restored_state = restored.get_view(Viewer::Admin);
+  assert_eq!(state.phase, restored_state.phase);
+  assert_eq!(state.table, restored_state.table);
+  assert_eq!(state.remaining_deck, restored_state.remaining_deck);
+  assert_eq!(state.total_pot, restored_state.total_pot);
+  assert_eq!(state.current_player_index, restored_state.current_player_index);
+  assert_eq!(state.dealer_index, restored_state.dealer_index);
+  assert_eq!(state.value_to_call, restored_state.value_to_call);
+  for (player, restored_player) in state.players.iter().zip(restored_state.players.iter()) {
+    assert_eq!(player.wallet, restored_player.wallet);
+    assert_eq!(player.money_on_table, restored_player.money_on_table);
+    assert_eq!(player.hole_cards, restored_player.hole_cards);
+  }
+}
+
+#[test]
+fn should_deal_the_same_cards_after_restoring() {
+  let mut game = Game::create(3, 1000);
+  game.set_seed(9);
+  play_to_the_flop(&mut game);
+
+  let mut restored = Game::create_from_snapshot(game.save_snapshot()).unwrap();
+  play_to_showdown(&mut game);
+  play_to_showdown(&mut restored);
+  assert_eq!(game.get_showdown(), restored.get_showdown());
+
+  game.next();
+  restored.next();
+  assert_eq!(
+    game.get_view(Viewer::Admin).players[0].hole_cards,
+    restored.get_view(Viewer::Admin).players[0].hole_cards
+  );
+}
+
+#[test]
+fn should_not_change_what_is_dealt_when_saving() {
+  let deal_to_showdown = |should_save: bool| {
+    let mut game = Game::create(3, 1000);
+    game.set_seed(9);
+    play_to_the_flop(&mut game);
+    if should_save {
+      game.save_snapshot();
+    }
+    play_to_showdown(&mut game);
+    game.get_showdown().to_vec()
+  };
+  assert_eq!(deal_to_showdown(false), deal_to_showdown(true));
+}
+
+#[test]
+fn should_not_restore_a_betting_round_for_different_players() {
+  let mut game = Game::create(3, 1000);
+  play_to_the_flop(&mut game);
+  let mut snapshot = game.save_snapshot();
+  snapshot.betting_round = BettingRound::create_for_players(5);
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+}
+
+#[test]
+fn should_not_restore_a_button_or_blinds_for_players_who_are_not_at_the_table() {
+  let mut game = Game::create(3, 1000);
+  play_to_the_flop(&mut game);
+
+  let mut snapshot = game.save_snapshot();
+  snapshot.dead_button = Some(7);
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+
+  let mut snapshot = game.save_snapshot();
+  snapshot.blind_positions = Some(BlindPositions {
+    small_blind: 0,
+    big_blind: 7,
+  });
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+}
+
+#[test]
+fn should_not_restore_a_phase_the_variant_does_not_have() {
+  let mut game = Game::create(3, 1000);
+  play_to_the_flop(&mut game);
+  let mut snapshot = game.save_snapshot();
+  snapshot.variant = "seven_card_stud".to_string();
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+}
+
+#[test]
+fn should_not_restore_streets_that_do_not_match_the_phase() {
+  let mut game = Game::create(3, 1000);
+  play_to_the_flop(&mut game);
+  let mut snapshot = game.save_snapshot();
+  snapshot.dealt_streets = 1;
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+}
+
+#[test]
+fn should_not_restore_a_deck_without_enough_cards_for_the_rest_of_the_hand() {
+  let mut game = Game::create(3, 1000);
+  play_to_the_flop(&mut game);
+  let mut snapshot = game.save_snapshot();
+  snapshot.available_cards = Deck::new();
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+}
+
+#[test]
+fn should_not_restore_a_card_that_has_been_dealt_twice() {
+  let mut game = Game::create(3, 1000);
+  play_to_the_flop(&mut game);
+  let mut snapshot = game.save_snapshot();
+  snapshot.available_cards.add_card(snapshot.table.get_cards()[0]);
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+
+  let mut snapshot = game.save_snapshot();
+  let hole_card = snapshot.active_seats[0].hand.get_cards()[0];
+  snapshot.active_seats[1].hand.add_card(hole_card);
+  assert!(Game::create_from_snapshot(snapshot).is_err());
+}
+
+#[test]
+fn should_restore_a_turn_that_has_used_more_time_than_is_left() {
+  let mut game = Game::create(2, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::from_secs(30),
+    time_bank: Duration::ZERO,
+  });
+  game.next();
+  let mut snapshot = game.save_snapshot();
+  snapshot.turn_time_used = Duration::MAX;
+
+  let mut restored = Game::create_from_snapshot(snapshot).unwrap();
+  assert_eq!(
+    Duration::ZERO,
+    restored.get_state(None).time_remaining.unwrap().action_time
+  );
+  assert!(restored.action_current_player_if_timed_out().is_some());
+}
+
+#[test]
+fn should_keep_the_variant_and_seats_when_restoring() {
+  let mut game = Game::create_with_variant(3, 1000, Box::new(SevenCardStud));
+  game.next();
+
+  let restored = Game::create_from_snapshot(game.save_snapshot()).unwrap();
+  assert_eq!("seven_card_stud", restored.get_state(None).variant.get_name());
+  assert_eq!(Phase::ThirdStreet, restored.get_state(None).phase);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn should_restore_a_game_saved_as_json() {
+  let mut game = Game::create(2, 1000);
+  play_to_the_flop(&mut game);
+
+  let json = serde_json::to_string(&game.save_snapshot()).unwrap();
+  let restored = Game::create_from_snapshot(serde_json::from_str(&json).unwrap()).unwrap();
+  assert_eq!(
+    game.get_view(Viewer::Admin).remaining_deck,
+    restored.get_view(Viewer::Admin).remaining_deck
+  );
+  assert_eq!(game.get_state(None).table, restored.get_state(None).table);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn should_not_restore_an_unknown_variant() {
+  let game = Game::create(2, 1000);
+  let json = serde_json::to_string(&game.save_snapshot())
+    .unwrap()
+    .replace("\"holdem\"", "\"razz\"");
+  assert!(Game::create_from_snapshot(serde_json::from_str(&json).unwrap()).is_err());
+}
+
+#[test]
+fn should_keep_the_time_used_on_the_current_turn() {
+  let mut game = Game::create(2, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::from_secs(30),
+    time_bank: Duration::ZERO,
+  });
+  let clock = Arc::new(ManualClock::default());
+  game.set_clock(clock.clone());
+  game.next();
+  clock.advance(Duration::from_secs(10));
+
+  // The restored game runs on the system clock, which may have ticked a little before the assertion.
+  let restored = Game::create_from_snapshot(game.save_snapshot()).unwrap();
+  let action_time = restored.get_state(None).time_remaining.unwrap().action_time;
+  assert!(action_time <= Duration::from_secs(20));
+  assert!(action_time > Duration::from_secs(19));
+}
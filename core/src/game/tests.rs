@@ -1,4 +1,7 @@
 use crate::game::*;
+use crate::variant::{Omaha, OmahaHiLo, SevenCardStud, ShortDeck};
+use std::sync::Arc;
+use std::time::Duration;
 
 fn call_and_next(game: &mut Game) {
   game.action_current_player(BettingAction::Call).unwrap();
@@ -102,13 +105,118 @@ fn should_select_the_player_past_blind_to_start_on_preflop() {
 }
 
 #[test]
-fn should_select_the_player_past_blind_to_start_on_preflop_circular() {
+fn should_let_the_dealer_post_the_small_blind_and_start_preflop_heads_up() {
   let mut game = Game::create(2, 1000);
   assert_eq!(Phase::Init, game.phase);
   game.next();
   assert_eq!(Phase::PreFlop, game.phase);
   assert_eq!(0, game.dealer_index);
+  assert_eq!(0, game.get_current_seat().unwrap().player_index);
+  let player_state = game.get_state(None).players;
+  assert_eq!(10, player_state[0].money_on_table);
+  assert_eq!(20, player_state[1].money_on_table);
+
+  for _ in 0..2 {
+    call_and_next(&mut game);
+  }
+  assert_eq!(Phase::Flop, game.phase);
+  assert_eq!(1, game.get_current_seat().unwrap().player_index);
+}
+
+#[test]
+fn should_move_the_button_and_blinds_between_hands_heads_up() {
+  let mut game = Game::create(2, 1000);
+  play_a_game_of_calls(&mut game);
+  game.next();
+  assert_eq!(1, game.get_state(None).dealer_index);
   assert_eq!(1, game.get_current_seat().unwrap().player_index);
+  let player_state = game.get_state(None).players;
+  assert_eq!(20, player_state[0].money_on_table);
+  assert_eq!(10, player_state[1].money_on_table);
+}
+
+#[test]
+fn should_eliminate_a_busted_dealer() {
+  let mut game = Game::create(3, 1000);
+  game.dealer_index = 1;
+  game.active_seats[1].wallet = 0;
+  game.next();
+  assert!(game.get_state(None).players[1].is_eliminated);
+  assert_eq!(2, game.get_state(None).dealer_index);
+}
+
+#[test]
+fn should_leave_a_dead_button_when_the_small_blind_busts() {
+  let mut game = Game::create(4, 1000);
+  play_a_game_of_calls(&mut game);
+  assert_eq!(0, game.get_state(None).dealer_index);
+  game.active_seats[1].wallet = 0;
+  game.next();
+
+  let state = game.get_state(None);
+  assert_eq!(1, state.dealer_index);
+  assert_eq!(10, state.players[2].money_on_table);
+  assert_eq!(20, state.players[3].money_on_table);
+  assert_eq!(0, game.get_current_seat().unwrap().player_index);
+
+  for _ in 0..3 {
+    call_and_next(&mut game);
+  }
+  assert_eq!(Phase::Flop, game.phase);
+  assert_eq!(2, game.get_current_seat().unwrap().player_index);
+}
+
+#[test]
+fn should_skip_the_small_blind_when_the_big_blind_busts() {
+  let mut game = Game::create(4, 1000);
+  play_a_game_of_calls(&mut game);
+  game.active_seats[2].wallet = 0;
+  game.next();
+
+  let state = game.get_state(None);
+  assert_eq!(1, state.dealer_index);
+  assert_eq!(20, state.total_pot);
+  assert_eq!(20, state.players[3].money_on_table);
+  assert_eq!(0, game.get_current_seat().unwrap().player_index);
+}
+
+#[test]
+fn should_post_missed_blinds_when_sitting_back_in() {
+  let mut game = Game::create(4, 1000);
+  play_a_game_of_calls(&mut game);
+  game.sit_out(3).unwrap();
+  play_a_game_of_calls(&mut game);
+  assert_eq!(30, game.get_state(None).players[3].missed_blinds);
+
+  game.sit_in(3).unwrap();
+  game.next();
+  let state = game.get_state(None);
+  assert_eq!(0, state.players[3].missed_blinds);
+  assert_eq!(10, state.players[0].money_on_table);
+  assert_eq!(20, state.players[1].money_on_table);
+  assert_eq!(20, state.players[3].money_on_table);
+  assert_eq!(60, state.total_pot);
+}
+
+#[test]
+fn should_not_lose_a_dead_blind_when_the_player_who_posted_it_folds() {
+  let mut game = Game::create(4, 1000);
+  play_a_game_of_calls(&mut game);
+  game.sit_out(3).unwrap();
+  play_a_game_of_calls(&mut game);
+  game.sit_in(3).unwrap();
+
+  game.next();
+  while game.phase != Phase::Init {
+    match game.get_current_player_index() {
+      Some(3) if game.phase == Phase::Flop => game.action_current_player(BettingAction::Fold).unwrap(),
+      Some(_) => game.action_current_player(BettingAction::Call).unwrap(),
+      None => {}
+    }
+    game.next();
+  }
+  let wallets = game.active_seats.iter().chain(game.inactive_seats.iter()).map(|s| s.wallet);
+  assert_eq!(4000, wallets.sum::<u32>());
 }
 
 #[test]
@@ -169,6 +277,314 @@ fn should_only_split_pot_between_players_who_have_not_folded() {
   assert_eq!(800, game.active_seats[1].wallet);
 }
 
+#[test]
+fn should_split_pot_between_high_and_low_hands_in_hi_lo_games() {
+  let mut game = Game::create_with_variant(3, 1000, Box::new(OmahaHiLo));
+
+  game.phase = Phase::PreFlop;
+  game.betting_round.set_new_start_position(0);
+  game.action_current_player(BettingAction::Raise(200)).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+
+  game.table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Club, Rank::Two),
+    Card::new(Suit::Spade, Rank::Seven),
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Diamond, Rank::Nine),
+  ]);
+  game.active_seats[0].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Spade, Rank::King),
+    Card::new(Suit::Diamond, Rank::Ten),
+    Card::new(Suit::Club, Rank::Ten),
+  ]);
+  game.active_seats[1].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Three),
+    Card::new(Suit::Spade, Rank::Four),
+    Card::new(Suit::Heart, Rank::Queen),
+    Card::new(Suit::Club, Rank::Queen),
+  ]);
+  game.active_seats[2].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Queen),
+    Card::new(Suit::Spade, Rank::Jack),
+    Card::new(Suit::Heart, Rank::Ten),
+    Card::new(Suit::Club, Rank::Jack),
+  ]);
+
+  game.finalize();
+  assert_eq!(1100, game.active_seats[0].wallet);
+  assert_eq!(1100, game.active_seats[1].wallet);
+  assert_eq!(800, game.active_seats[2].wallet);
+}
+
+#[test]
+fn should_not_split_pot_for_low_hands_outside_of_hi_lo_games() {
+  let mut game = Game::create(2, 1000);
+
+  game.phase = Phase::PreFlop;
+  game.betting_round.set_new_start_position(0);
+  game.action_current_player(BettingAction::Raise(200)).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+
+  game.table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Club, Rank::Two),
+    Card::new(Suit::Spade, Rank::Seven),
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Diamond, Rank::Nine),
+  ]);
+  game.active_seats[0].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Spade, Rank::King),
+  ]);
+  game.active_seats[1].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::Three),
+    Card::new(Suit::Spade, Rank::Four),
+  ]);
+
+  game.finalize();
+  assert_eq!(1200, game.active_seats[0].wallet);
+  assert_eq!(800, game.active_seats[1].wallet);
+}
+
+#[test]
+fn should_only_deal_short_deck_cards_in_short_deck_games() {
+  let mut game = Game::create_with_variant(6, 1000, Box::new(ShortDeck));
+  game.next();
+  assert_eq!(Phase::PreFlop, game.phase);
+  for p in &game.active_seats {
+    assert!(p.hand.get_cards().iter().all(|c| Deck::short_deck().has_card(*c)));
+  }
+  assert_eq!(36 - 12, game.available_cards.get_cards().len());
+}
+
+#[test]
+fn should_rank_flushes_above_full_houses_in_short_deck_games() {
+  let mut game = Game::create_with_variant(2, 1000, Box::new(ShortDeck));
+
+  game.phase = Phase::PreFlop;
+  game.betting_round.set_new_start_position(0);
+  game.action_current_player(BettingAction::Raise(200)).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+
+  game.table = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Ace),
+    Card::new(Suit::Heart, Rank::Seven),
+    Card::new(Suit::Spade, Rank::Seven),
+    Card::new(Suit::Heart, Rank::King),
+    Card::new(Suit::Diamond, Rank::Nine),
+  ]);
+  game.active_seats[0].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Spade, Rank::King),
+  ]);
+  game.active_seats[1].hand = Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Six),
+    Card::new(Suit::Heart, Rank::Ten),
+  ]);
+
+  game.finalize();
+  assert_eq!(800, game.active_seats[0].wallet);
+  assert_eq!(1200, game.active_seats[1].wallet);
+}
+
+#[test]
+fn should_deal_cards_for_each_street_of_the_variant() {
+  let mut game = Game::create_with_variant(3, 1000, Box::new(Omaha));
+  game.next();
+  assert_eq!(Phase::PreFlop, game.phase);
+  for p in &game.active_seats {
+    assert_eq!(4, p.hand.get_cards().len());
+  }
+  assert_eq!(0, game.table.get_cards().len());
+
+  for _ in 0..3 {
+    call_and_next(&mut game);
+  }
+  assert_eq!(Phase::Flop, game.phase);
+  assert_eq!(3, game.table.get_cards().len());
+
+  while game.phase != Phase::Showdown {
+    call_and_next(&mut game);
+  }
+  assert_eq!(5, game.table.get_cards().len());
+  game.next();
+  assert_eq!(Phase::Init, game.phase);
+}
+
+#[test]
+fn should_deal_the_rest_of_the_table_when_going_to_showdown_early() {
+  let mut game = Game::create_with_variant(2, 1000, Box::new(Omaha));
+  game.next();
+  game.action_current_player(BettingAction::AllIn).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.next();
+  assert_eq!(Phase::Showdown, game.phase);
+  game.next();
+  assert_eq!(5, game.table.get_cards().len());
+  assert_eq!(2000, game.active_seats.iter().map(|s| s.wallet).sum::<u32>());
+}
+
+#[test]
+fn should_deal_down_and_up_cards_on_third_street_in_stud_games() {
+  let mut game = Game::create_with_variant(4, 1000, Box::new(SevenCardStud));
+  game.next();
+  assert_eq!(Phase::ThirdStreet, game.phase);
+  assert_eq!(0, game.table.get_cards().len());
+
+  let state = game.get_state(None);
+  for (seat, player) in game.active_seats.iter().zip(state.players.iter()) {
+    assert_eq!(3, seat.hand.get_cards().len());
+    assert_eq!(1, player.visible_cards.get_cards().len());
+    assert!(seat.hand.has_card(player.visible_cards.get_cards()[0]));
+  }
+  assert_eq!(4 * 2 + 5, state.total_pot);
+}
+
+#[test]
+fn should_post_the_bring_in_for_the_lowest_up_card_in_stud_games() {
+  let mut game = Game::create_with_variant(4, 1000, Box::new(SevenCardStud));
+  game.next();
+  let state = game.get_state(None);
+  let up_card_ranks = state
+    .players
+    .iter()
+    .map(|p| p.visible_cards.get_cards()[0].rank as u8)
+    .collect::<Vec<_>>();
+  let bring_in_player = state.players.iter().position(|p| p.money_on_table == 5).unwrap();
+  assert_eq!(*up_card_ranks.iter().min().unwrap(), up_card_ranks[bring_in_player]);
+  assert_eq!(1000 - 2 - 5, state.players[bring_in_player].wallet);
+}
+
+#[test]
+fn should_deal_seven_cards_with_four_showing_by_showdown_in_stud_games() {
+  let mut game = Game::create_with_variant(3, 1000, Box::new(SevenCardStud));
+  game.next();
+  while game.phase != Phase::Showdown {
+    call_and_next(&mut game);
+  }
+  for seat in &game.active_seats {
+    assert_eq!(7, seat.hand.get_cards().len());
+    assert_eq!(4, seat.visible_cards.get_cards().len());
+  }
+  assert_eq!(0, game.table.get_cards().len());
+  game.next();
+  assert_eq!(Phase::Init, game.phase);
+  assert_eq!(3000, game.active_seats.iter().map(|s| s.wallet).sum::<u32>());
+}
+
+#[test]
+fn should_fold_when_out_of_time_with_money_to_call() {
+  let mut game = Game::create(3, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::ZERO,
+    time_bank: Duration::ZERO,
+  });
+  game.next();
+  let player_index = game.get_current_player_index().unwrap();
+  assert_eq!(Some(BettingAction::Fold), game.action_current_player_if_timed_out());
+  assert!(game.get_state(None).players[player_index as usize].is_folded);
+}
+
+#[test]
+fn should_check_when_out_of_time_with_nothing_to_call() {
+  let mut game = Game::create(2, 1000);
+  game.next();
+  for _ in 0..2 {
+    call_and_next(&mut game);
+  }
+  assert_eq!(Phase::Flop, game.phase);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::ZERO,
+    time_bank: Duration::ZERO,
+  });
+  assert_eq!(Some(BettingAction::Call), game.action_current_player_if_timed_out());
+  assert!(game.get_state(None).players.iter().all(|p| !p.is_folded));
+}
+
+#[test]
+fn should_not_time_out_while_there_is_time_remaining() {
+  let mut game = Game::create(2, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::from_secs(30),
+    time_bank: Duration::from_secs(60),
+  });
+  let clock = Arc::new(ManualClock::default());
+  game.set_clock(clock.clone());
+  game.next();
+  clock.advance(Duration::from_secs(20));
+  assert_eq!(None, game.action_current_player_if_timed_out());
+
+  let time_remaining = game.get_state(None).time_remaining.unwrap();
+  assert_eq!(Duration::from_secs(10), time_remaining.action_time);
+  assert_eq!(Duration::from_secs(60), time_remaining.time_bank);
+}
+
+#[test]
+fn should_use_the_time_bank_once_the_action_time_has_run_out() {
+  let mut game = Game::create(2, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::ZERO,
+    time_bank: Duration::from_secs(10),
+  });
+  let clock = Arc::new(ManualClock::default());
+  game.set_clock(clock.clone());
+  game.next();
+  let player_index = game.get_current_player_index().unwrap();
+  clock.advance(Duration::from_secs(4));
+  assert_eq!(None, game.action_current_player_if_timed_out());
+  game.action_current_player(BettingAction::Call).unwrap();
+  assert_eq!(
+    Duration::from_secs(6),
+    game.active_seats[player_index as usize].time_bank
+  );
+
+  clock.advance(Duration::from_secs(20));
+  assert!(game.action_current_player_if_timed_out().is_some());
+}
+
+#[test]
+fn game_state_should_not_have_time_remaining_without_a_time_limit() {
+  let mut game = Game::create(2, 1000);
+  game.next();
+  assert_eq!(None, game.get_state(None).time_remaining);
+}
+
+#[test]
+fn should_record_the_showdown_hands_of_unfolded_players() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  game.action_current_player(BettingAction::Fold).unwrap();
+  while game.phase != Phase::Showdown {
+    call_and_next(&mut game);
+  }
+  game.next();
+
+  let showdown = game.get_showdown();
+  assert_eq!(2, showdown.len());
+  assert_eq!(40, showdown.iter().map(|h| h.winnings).sum::<u32>());
+  for hand in showdown {
+    assert_eq!(2, hand.hand.get_cards().len());
+    assert_eq!(
+      game.get_state(None).players[hand.player_index as usize].visible_cards,
+      hand.hand
+    );
+  }
+}
+
+#[test]
+fn should_not_record_a_showdown_when_everyone_else_folds() {
+  let mut game = Game::create(2, 1000);
+  game.next();
+  game.action_current_player(BettingAction::Fold).unwrap();
+  game.next();
+  assert_eq!(Phase::Showdown, game.phase);
+  game.next();
+  assert!(game.get_showdown().is_empty());
+}
+
 #[test]
 fn should_decrement_seat_wallet_on_bet() {
   let mut game = Game::create(2, 1000);
@@ -298,16 +714,196 @@ fn game_state_should_return_correct_player_info() {
   let player = player_state[0];
   assert_eq!(800, player.wallet);
   assert_eq!(200, player.money_on_table);
-  assert_eq!(false, player.is_folded);
+  assert!(!player.is_folded);
 
   let player = player_state[1];
   assert_eq!(1000, player.wallet);
   assert_eq!(0, player.money_on_table);
-  assert_eq!(true, player.is_folded);
+  assert!(player.is_folded);
 
   assert_eq!(2, player_state.len());
 }
 
+#[test]
+fn game_state_should_keep_eliminated_players_at_their_player_index() {
+  let mut game = Game::create(4, 1000);
+  game.dealer_index = 3;
+  game.active_seats[1].wallet = 10;
+  game.next();
+
+  let player_state = game.get_state(None).players;
+  assert_eq!(4, player_state.len());
+  for (idx, player) in player_state.iter().enumerate() {
+    assert_eq!(idx as u8, player.player_index);
+    assert_eq!(idx == 1, player.is_eliminated);
+  }
+  assert_eq!(10, player_state[1].wallet);
+  assert!(player_state[1].is_folded);
+  assert_eq!(0, player_state[0].money_on_table);
+  assert_eq!(10, player_state[2].money_on_table);
+  assert_eq!(20, player_state[3].money_on_table);
+}
+
+#[test]
+fn game_state_should_keep_eliminated_players_across_hands() {
+  let mut game = Game::create(3, 1000);
+  game.active_seats[0].wallet = 0;
+  play_a_game_of_calls(&mut game);
+  play_a_game_of_calls(&mut game);
+
+  let player_state = game.get_state(None).players;
+  assert_eq!(3, player_state.len());
+  assert!(player_state[0].is_eliminated);
+  assert_eq!(
+    vec![1, 2],
+    game.active_seats.iter().map(|s| s.player_index).collect::<Vec<_>>()
+  );
+}
+
+#[test]
+fn should_seat_new_players_between_hands() {
+  let mut game = Game::create(2, 1000);
+  assert_eq!(Ok(2), game.seat_player(500));
+  game.next();
+  assert_eq!(3, game.active_seats.len());
+  assert_eq!(2, game.active_seats[2].hand.get_cards().len());
+  assert_eq!(500, game.get_state(None).players[2].wallet);
+}
+
+#[test]
+fn should_only_change_seats_between_hands() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  assert!(game.seat_player(500).is_err());
+  assert!(game.sit_out(0).is_err());
+  assert!(game.leave(0).is_err());
+  assert!(game.rebuy(0, 100).is_err());
+}
+
+#[test]
+fn should_only_count_players_dealt_into_the_hand() {
+  let mut game = Game::create(4, 1000);
+  game.sit_out(1).unwrap();
+  game.next();
+  assert_eq!(3, game.get_state(None).get_num_players_in_hand());
+
+  game.action_current_player(BettingAction::Fold).unwrap();
+  assert_eq!(2, game.get_state(None).get_num_players_in_hand());
+}
+
+#[test]
+fn should_not_deal_in_players_who_are_sitting_out() {
+  let mut game = Game::create(3, 1000);
+  game.sit_out(1).unwrap();
+  game.next();
+  let player_state = game.get_state(None).players;
+  assert!(player_state[1].is_sitting_out);
+  assert_eq!(0, player_state[1].money_on_table);
+  assert_eq!(
+    vec![0, 2],
+    game.active_seats.iter().map(|s| s.player_index).collect::<Vec<_>>()
+  );
+
+  while game.phase != Phase::Showdown {
+    call_and_next(&mut game);
+  }
+  game.next();
+  game.sit_in(1).unwrap();
+  game.next();
+  assert_eq!(2, game.active_seats[1].hand.get_cards().len());
+  assert!(!game.get_state(None).players[1].is_sitting_out);
+}
+
+#[test]
+fn should_cash_out_players_who_leave() {
+  let mut game = Game::create(3, 1000);
+  assert_eq!(Ok(1000), game.leave(0));
+  let player_state = game.get_state(None).players;
+  assert!(player_state[0].has_left);
+  assert_eq!(0, player_state[0].wallet);
+  assert!(game.rebuy(0, 500).is_err());
+  assert!(game.leave(0).is_err());
+}
+
+#[test]
+fn should_allow_every_player_to_leave_or_sit_out() {
+  let mut game = Game::create(2, 1000);
+  game.sit_out(0).unwrap();
+  game.sit_out(1).unwrap();
+  assert!(game.get_state(None).players.iter().all(|p| p.is_sitting_out));
+  assert_eq!(None, game.next());
+
+  game.sit_in(0).unwrap();
+  game.sit_in(1).unwrap();
+  assert_eq!(Some(Phase::PreFlop), game.next());
+
+  let mut game = Game::create(2, 1000);
+  game.leave(0).unwrap();
+  game.leave(1).unwrap();
+  assert!(game.get_state(None).players.iter().all(|p| p.has_left));
+  assert_eq!(None, game.next());
+}
+
+#[test]
+fn should_move_the_dealer_to_the_next_seat_when_the_dealer_leaves() {
+  let mut game = Game::create(4, 1000);
+  play_a_game_of_calls(&mut game);
+  assert_eq!(0, game.get_state(None).dealer_index);
+  game.leave(0).unwrap();
+  game.next();
+  assert_eq!(1, game.get_state(None).dealer_index);
+  let player_state = game.get_state(None).players;
+  assert_eq!(10, player_state[2].money_on_table);
+  assert_eq!(20, player_state[3].money_on_table);
+}
+
+#[test]
+fn should_keep_the_dealer_when_an_earlier_seat_returns() {
+  let mut game = Game::create(4, 1000);
+  game.sit_out(0).unwrap();
+  play_a_game_of_calls(&mut game);
+  assert_eq!(1, game.get_state(None).dealer_index);
+  game.sit_in(0).unwrap();
+  game.next();
+  assert_eq!(2, game.get_state(None).dealer_index);
+}
+
+#[test]
+fn should_top_up_and_rebuy_eliminated_players() {
+  let mut game = Game::create(3, 1000);
+  game.rebuy(0, 500).unwrap();
+  assert_eq!(1500, game.active_seats[0].wallet);
+
+  game.active_seats[1].wallet = 0;
+  play_a_game_of_calls(&mut game);
+  assert!(game.get_state(None).players[1].is_eliminated);
+  game.rebuy(1, 500).unwrap();
+  let player_state = game.get_state(None).players;
+  assert!(!player_state[1].is_eliminated);
+  assert_eq!(500, player_state[1].wallet);
+}
+
+#[test]
+fn should_post_the_new_blind_after_it_changes() {
+  let mut game = Game::create(3, 1000);
+  assert_eq!(Ok(()), game.set_blind(100));
+  game.next();
+  assert!(game.set_blind(200).is_err());
+  assert_eq!(150, game.get_state(None).total_pot);
+}
+
+#[test]
+fn should_deal_the_same_cards_for_the_same_seed() {
+  let deal_with_seed = |seed: u64| {
+    let mut game = Game::create(3, 1000);
+    game.set_seed(seed);
+    game.next();
+    game.active_seats.iter().map(|s| s.hand).collect::<Vec<_>>()
+  };
+  assert_eq!(deal_with_seed(7), deal_with_seed(7));
+  assert_ne!(deal_with_seed(7), deal_with_seed(8));
+}
+
 #[test]
 fn game_when_actioning_player_should_use_whole_wallet_when_going_all_in() {
   let mut game = Game::create(2, 1000);
@@ -362,3 +958,439 @@ fn game_when_actioning_player_should_convert_call_to_all_in() {
   game.next();
   assert_eq!(Phase::Showdown, game.get_state(None).phase);
 }
+
+fn go_all_in_preflop(game: &mut Game) {
+  game.next();
+  game.action_current_player(BettingAction::AllIn).unwrap();
+  game.action_current_player(BettingAction::AllIn).unwrap();
+  game.next();
+}
+
+#[test]
+fn should_report_all_in_equity_before_the_runout() {
+  let mut game = Game::create(2, 1000);
+  go_all_in_preflop(&mut game);
+
+  assert_eq!(Phase::Showdown, game.phase);
+  let equity = game.get_state(None).all_in_equity;
+  assert_eq!(vec![0, 1], equity.iter().map(|e| e.player_index).collect::<Vec<_>>());
+  let total_equity = equity.iter().map(|e| e.equity).sum::<f32>();
+  assert!((total_equity - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn should_not_report_equity_without_an_all_in() {
+  let mut game = Game::create(2, 1000);
+  game.next();
+  call_and_next(&mut game);
+  call_and_next(&mut game);
+  assert!(game.get_state(None).all_in_equity.is_empty());
+}
+
+#[test]
+fn should_run_the_board_once_by_default() {
+  let mut game = Game::create(2, 1000);
+  go_all_in_preflop(&mut game);
+  game.next();
+  assert!(game.get_runouts().is_empty());
+}
+
+#[test]
+fn should_not_allow_running_the_board_zero_times() {
+  let mut game = Game::create(2, 1000);
+  assert!(game.set_run_it_times(0).is_err());
+}
+
+#[test]
+fn should_run_the_board_multiple_times_and_split_the_pot() {
+  let mut game = Game::create(2, 1000);
+  game.set_seed(7);
+  game.set_run_it_times(3).unwrap();
+  go_all_in_preflop(&mut game);
+  let hands = game.active_seats.iter().map(|s| s.hand).collect::<Vec<_>>();
+  game.next();
+
+  let runouts = game.get_runouts();
+  assert_eq!(3, runouts.len());
+  for runout in runouts {
+    assert_eq!(5, runout.table.get_cards().len());
+    assert_eq!(2000, runout.hands.iter().map(|h| h.winnings).sum::<u32>());
+    assert_eq!(hands, runout.hands.iter().map(|h| h.hand).collect::<Vec<_>>());
+  }
+  let all_boards = runouts.iter().fold(Deck::new(), |deck, r| deck + r.table);
+  assert_eq!(15, all_boards.get_cards().len());
+
+  let per_player = |idx: u8| {
+    runouts
+      .iter()
+      .flat_map(|r| r.hands.iter())
+      .filter(|h| h.player_index == idx)
+      .map(|h| h.winnings)
+      .sum::<u32>()
+  };
+  let wallets = game.active_seats.iter().map(|s| s.wallet).collect::<Vec<_>>();
+  assert_eq!(2000, wallets.iter().sum::<u32>());
+  assert!(wallets[0].abs_diff(per_player(0) / 3) <= 1);
+  assert!(wallets[1].abs_diff(per_player(1) / 3) <= 1);
+}
+
+#[test]
+fn should_only_share_the_cards_dealt_before_the_all_in_between_runs() {
+  let mut game = Game::create(2, 1000);
+  game.set_run_it_times(2).unwrap();
+  game.next();
+  call_and_next(&mut game);
+  call_and_next(&mut game);
+  assert_eq!(Phase::Flop, game.phase);
+  let flop = game.table;
+  game.action_current_player(BettingAction::AllIn).unwrap();
+  game.action_current_player(BettingAction::AllIn).unwrap();
+  game.next();
+  game.next();
+
+  let runouts = game.get_runouts();
+  assert_eq!(2, runouts.len());
+  let later_cards = runouts.iter().map(|r| r.table - flop).collect::<Vec<_>>();
+  assert_eq!(2, later_cards[0].get_cards().len());
+  assert_eq!(Deck::new(), later_cards[0] - (later_cards[0] - later_cards[1]));
+}
+
+#[test]
+fn should_only_run_the_board_as_many_times_as_the_deck_allows() {
+  let mut game = Game::create(2, 1000);
+  game.set_run_it_times(20).unwrap();
+  go_all_in_preflop(&mut game);
+  game.next();
+  assert_eq!(9, game.get_runouts().len());
+  assert_eq!(2000, game.active_seats.iter().map(|s| s.wallet).sum::<u32>());
+}
+
+#[test]
+fn should_deal_the_same_board_whether_or_not_equity_was_calculated() {
+  let mut all_in_game = Game::create(2, 1000);
+  all_in_game.set_seed(11);
+  go_all_in_preflop(&mut all_in_game);
+  assert!(!all_in_game.get_state(None).all_in_equity.is_empty());
+  all_in_game.next();
+
+  let mut called_game = Game::create(2, 1000);
+  called_game.set_seed(11);
+  called_game.next();
+  while called_game.phase != Phase::Showdown {
+    call_and_next(&mut called_game);
+  }
+  assert_eq!(called_game.table, all_in_game.table);
+}
+
+#[test]
+fn should_not_allow_a_rake_over_one_hundred_percent() {
+  let mut game = Game::create(2, 1000);
+  let rake = Rake {
+    percentage: 101.0,
+    cap: 10,
+    no_flop_no_drop: false,
+  };
+  assert!(game.set_rake(Some(rake)).is_err());
+}
+
+#[test]
+fn should_take_a_capped_rake_from_the_pot() {
+  let mut game = Game::create(2, 1000);
+  let rake = Rake {
+    percentage: 5.0,
+    cap: 30,
+    no_flop_no_drop: true,
+  };
+  game.set_rake(Some(rake)).unwrap();
+
+  play_a_game_of_calls(&mut game);
+  assert_eq!(2, game.get_hand_rake());
+
+  go_all_in_preflop(&mut game);
+  game.next();
+  assert_eq!(30, game.get_hand_rake());
+  assert_eq!(32, game.get_total_rake());
+  assert_eq!(2000 - 32, game.active_seats.iter().map(|s| s.wallet).sum::<u32>());
+}
+
+#[test]
+fn should_take_the_same_rake_when_running_the_board_multiple_times() {
+  let mut game = Game::create(2, 1000);
+  let rake = Rake {
+    percentage: 5.0,
+    cap: 30,
+    no_flop_no_drop: true,
+  };
+  game.set_rake(Some(rake)).unwrap();
+  game.set_run_it_times(2).unwrap();
+
+  go_all_in_preflop(&mut game);
+  game.next();
+  assert_eq!(2, game.get_runouts().len());
+  assert_eq!(30, game.get_hand_rake());
+  assert_eq!(2000 - 30, game.active_seats.iter().map(|s| s.wallet).sum::<u32>());
+}
+
+#[test]
+fn should_not_take_a_rake_when_there_is_no_flop() {
+  let mut game = Game::create(2, 1000);
+  let rake = Rake {
+    percentage: 10.0,
+    cap: 100,
+    no_flop_no_drop: true,
+  };
+  game.set_rake(Some(rake)).unwrap();
+
+  game.next();
+  game.action_current_player(BettingAction::Raise(100)).unwrap();
+  game.action_current_player(BettingAction::Fold).unwrap();
+  game.next();
+  game.next();
+  assert_eq!(0, game.get_hand_rake());
+  assert_eq!(2000, game.active_seats.iter().map(|s| s.wallet).sum::<u32>());
+  assert_eq!(0, game.table.get_cards().len());
+}
+
+#[test]
+fn should_show_first_from_the_last_aggressor_on_the_river() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  while game.phase != Phase::River {
+    call_and_next(&mut game);
+  }
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.action_current_player(BettingAction::Raise(40)).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.next();
+  game.next();
+
+  let order = game.get_showdown().iter().map(|h| h.player_index).collect::<Vec<_>>();
+  assert_eq!(vec![2, 0, 1], order);
+}
+
+#[test]
+fn should_show_first_from_the_left_of_the_dealer_without_a_river_bet() {
+  let mut game = Game::create(3, 1000);
+  play_a_game_of_calls(&mut game);
+  let order = game.get_showdown().iter().map(|h| h.player_index).collect::<Vec<_>>();
+  assert_eq!(0, game.get_state(None).dealer_index);
+  assert_eq!(vec![1, 2, 0], order);
+}
+
+#[test]
+fn should_let_losing_players_muck_their_hands() {
+  let mut game = Game::create(3, 1000);
+  game.set_seed(3);
+  for idx in 0..3 {
+    game.set_muck_losing_hands(idx, true).unwrap();
+  }
+  play_a_game_of_calls(&mut game);
+
+  let state = game.get_state(None);
+  let showdown = game.get_showdown();
+  assert_eq!(3, showdown.len());
+  assert!(showdown.iter().any(|h| h.is_mucked));
+  for hand in showdown {
+    let visible_cards = state.players[hand.player_index as usize].visible_cards;
+    if hand.is_mucked {
+      assert_eq!(0, hand.winnings);
+      assert_eq!(Deck::new(), hand.hand);
+      assert_eq!(Deck::new(), visible_cards);
+    } else {
+      assert_eq!(2, visible_cards.get_cards().len());
+    }
+  }
+}
+
+#[test]
+fn should_always_show_the_hand_of_the_last_aggressor() {
+  let mut game = Game::create(3, 1000);
+  game.set_seed(1);
+  for idx in 0..3 {
+    game.set_muck_losing_hands(idx, true).unwrap();
+  }
+  game.next();
+  while game.phase != Phase::River {
+    call_and_next(&mut game);
+  }
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.action_current_player(BettingAction::Raise(40)).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.action_current_player(BettingAction::Call).unwrap();
+  game.next();
+  game.next();
+
+  let last_aggressor = &game.get_showdown()[0];
+  assert_eq!(2, last_aggressor.player_index);
+  assert_eq!(0, last_aggressor.winnings);
+  assert!(!last_aggressor.is_mucked);
+  assert_eq!(2, game.get_state(None).players[2].visible_cards.get_cards().len());
+}
+
+#[test]
+fn should_table_all_in_hands_before_the_runout() {
+  let mut game = Game::create(2, 1000);
+  game.set_muck_losing_hands(0, true).unwrap();
+  game.set_muck_losing_hands(1, true).unwrap();
+  go_all_in_preflop(&mut game);
+
+  let state = game.get_state(None);
+  for (seat, player) in game.active_seats.iter().zip(state.players.iter()) {
+    assert_eq!(seat.hand, player.visible_cards);
+  }
+  game.next();
+  assert!(game.get_showdown().iter().all(|h| !h.is_mucked));
+}
+
+#[test]
+fn should_only_show_a_player_their_own_hole_cards() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  for player_index in 0..3 {
+    let state = game.get_view(Viewer::Player(player_index));
+    assert_eq!(None, state.remaining_deck);
+    for player in state.players {
+      if player.player_index == player_index {
+        assert_eq!(Some(state.hand), player.hole_cards);
+        assert_eq!(2, state.hand.get_cards().len());
+      } else {
+        assert_eq!(None, player.hole_cards);
+      }
+    }
+  }
+}
+
+#[test]
+fn should_not_show_hole_cards_to_spectators() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  let state = game.get_view(Viewer::Spectator);
+  assert_eq!(Deck::new(), state.hand);
+  assert_eq!(None, state.remaining_deck);
+  assert!(state.players.iter().all(|p| p.hole_cards.is_none()));
+}
+
+#[test]
+fn should_show_every_hand_and_the_deck_to_admins() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  let state = game.get_view(Viewer::Admin);
+  let mut all_cards = state.remaining_deck.unwrap();
+  for player in state.players {
+    all_cards = all_cards + player.hole_cards.unwrap();
+  }
+  assert_eq!(52, all_cards.get_cards().len());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn should_serialize_the_game_state() {
+  let mut game = Game::create_with_variant(2, 1000, Box::new(Omaha));
+  game.next();
+  let state = game.get_view(Viewer::Player(0));
+
+  let json = serde_json::to_value(&state).unwrap();
+  assert_eq!("omaha", json["variant"]);
+  assert_eq!("PreFlop", json["phase"]);
+  assert_eq!(4, json["hand"].as_array().unwrap().len());
+  assert!(json["players"][1]["hole_cards"].is_null());
+
+  let restored: GameState = serde_json::from_value(json).unwrap();
+  assert_eq!(state.hand, restored.hand);
+  assert_eq!(state.phase, restored.phase);
+  assert_eq!(state.total_pot, restored.total_pot);
+  assert_eq!("omaha", restored.variant.get_name());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn should_serialize_betting_actions() {
+  assert_eq!("\"Call\"", serde_json::to_string(&BettingAction::Call).unwrap());
+  assert_eq!(
+    "{\"Raise\":40}",
+    serde_json::to_string(&BettingAction::Raise(40)).unwrap()
+  );
+  assert_eq!(
+    BettingAction::AllIn,
+    serde_json::from_str::<BettingAction>("\"AllIn\"").unwrap()
+  );
+}
+
+#[test]
+fn should_log_the_actions_of_the_hand() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  game.action_current_player(BettingAction::Raise(40)).unwrap();
+  game.action_current_player(BettingAction::Fold).unwrap();
+  assert_eq!(
+    vec![(0, BettingAction::Raise(40)), (1, BettingAction::Fold)],
+    game.get_hand_actions()
+  );
+}
+
+#[test]
+fn should_undo_the_last_action() {
+  let mut game = Game::create(3, 1000);
+  game.next();
+  let state = game.get_view(Viewer::Admin);
+  game.action_current_player(BettingAction::Raise(100)).unwrap();
+
+  game.undo_last_action().unwrap();
+  let undone_state = game.get_view(Viewer::Admin);
+  assert_eq!(state.total_pot, undone_state.total_pot);
+  assert_eq!(state.current_player_index, undone_state.current_player_index);
+  assert_eq!(state.players[0].wallet, undone_state.players[0].wallet);
+  assert!(game.get_hand_actions().is_empty());
+  assert!(game.undo_last_action().is_err());
+
+  game.action_current_player(BettingAction::Fold).unwrap();
+  assert_eq!(vec![(0, BettingAction::Fold)], game.get_hand_actions());
+}
+
+#[test]
+fn should_undo_back_to_the_previous_street() {
+  let mut game = Game::create(2, 1000);
+  game.next();
+  call_and_next(&mut game);
+  call_and_next(&mut game);
+  assert_eq!(Phase::Flop, game.phase);
+
+  game.undo_last_action().unwrap();
+  assert_eq!(Phase::PreFlop, game.phase);
+  assert_eq!(0, game.table.get_cards().len());
+  assert_eq!(48, game.available_cards.get_cards().len());
+  assert_eq!(Some(1), game.get_current_player_index());
+}
+
+#[test]
+fn should_rewind_to_the_start_of_a_street_and_deal_the_same_cards() {
+  let mut game = Game::create(2, 1000);
+  game.set_seed(11);
+  game.next();
+  call_and_next(&mut game);
+  call_and_next(&mut game);
+  let flop_state = game.get_view(Viewer::Admin);
+  call_and_next(&mut game);
+  call_and_next(&mut game);
+  let turn_table = game.table;
+
+  game.rewind_to_street(Phase::Flop).unwrap();
+  assert_eq!(Phase::Flop, game.phase);
+  assert_eq!(flop_state.table, game.table);
+  assert_eq!(flop_state.total_pot, game.get_state(None).total_pot);
+  assert_eq!(2, game.get_hand_actions().len());
+
+  game.action_current_player(BettingAction::Raise(40)).unwrap();
+  call_and_next(&mut game);
+  assert_eq!(turn_table, game.table);
+  assert!(game.rewind_to_street(Phase::River).is_err());
+}
+
+#[test]
+fn should_not_undo_actions_from_a_finished_hand() {
+  let mut game = Game::create(2, 1000);
+  play_a_game_of_calls(&mut game);
+  assert!(game.get_hand_actions().is_empty());
+  assert!(game.undo_last_action().is_err());
+}
@@ -3,3 +3,8 @@ pub mod deck;
 pub mod evaluator;
 pub mod game;
 pub mod player;
+#[cfg(feature = "serde")]
+pub mod protocol;
+pub mod runner;
+pub mod tournament;
+pub mod variant;
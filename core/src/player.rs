@@ -1,7 +1,13 @@
 mod basic_player;
 mod calling_player;
+#[cfg(feature = "serde")]
+mod external_player;
+mod pending_action;
 mod types;
 
 pub use basic_player::BasicPlayer;
 pub use calling_player::CallingPlayer;
+#[cfg(feature = "serde")]
+pub use external_player::ExternalPlayer;
+pub use pending_action::{ActionSender, PendingAction};
 pub use types::Player;
@@ -1,4 +1,4 @@
-use super::Player;
+use super::{PendingAction, Player};
 use crate::game::{BettingAction, GameState};
 
 pub struct CallingPlayer {
@@ -6,11 +6,11 @@ pub struct CallingPlayer {
 }
 
 impl Player for CallingPlayer {
-  fn request_action(&self, info: GameState) -> BettingAction {
-    if info.wallet > info.value_to_call {
+  fn request_action(&mut self, info: GameState) -> PendingAction {
+    PendingAction::ready(if info.wallet > info.value_to_call {
       BettingAction::Call
     } else {
       BettingAction::AllIn
-    }
+    })
   }
 }
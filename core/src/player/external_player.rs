@@ -0,0 +1,122 @@
+use super::{ActionSender, PendingAction, Player};
+use crate::game::{BettingAction, GameState};
+use crate::protocol::{ActionRequest, ActionResponse};
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Default)]
+struct Responses {
+  request: Option<(u64, ActionSender)>,
+  has_exited: bool,
+}
+
+type SharedResponses = Arc<Mutex<Responses>>;
+
+pub struct ExternalPlayer {
+  child: Child,
+  requests: Sender<String>,
+  responses: SharedResponses,
+  next_request_id: u64,
+  timeout: Duration,
+}
+
+// Once the bot has gone, dropping the sender lets a pending request fold straight away.
+fn set_exited(responses: &SharedResponses) {
+  let mut responses = responses.lock().unwrap();
+  responses.has_exited = true;
+  responses.request = None;
+}
+
+// Writes happen on their own thread so that a bot that stops reading can only run out its timeout.
+fn write_requests(mut stdin: ChildStdin, requests: Receiver<String>, responses: SharedResponses) {
+  for request in requests {
+    if writeln!(stdin, "{}", request).is_err() {
+      break;
+    }
+  }
+  set_exited(&responses);
+}
+
+fn read_responses(stdout: ChildStdout, responses: SharedResponses) {
+  for line in BufReader::new(stdout).lines() {
+    let Ok(line) = line else { break };
+    let mut responses = responses.lock().unwrap();
+    // Replies to requests that have already timed out are skipped, but a reply that can not be read at all folds
+    // whatever is waiting.
+    match serde_json::from_str::<ActionResponse>(&line) {
+      Ok(response) if responses.request.as_ref().is_some_and(|(id, _)| *id == response.id) => {
+        responses.request.take().unwrap().1.send(response.action);
+      }
+      Ok(_) => {}
+      Err(_) => {
+        if let Some((_, sender)) = responses.request.take() {
+          sender.send(BettingAction::Fold);
+        }
+      }
+    }
+  }
+  set_exited(&responses);
+}
+
+impl ExternalPlayer {
+  pub fn create(command: &mut Command, timeout: Duration) -> io::Result<ExternalPlayer> {
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+    let stdin = child.stdin.take().unwrap();
+    let stdout = child.stdout.take().unwrap();
+    let responses = SharedResponses::default();
+    let (requests, request_receiver) = channel();
+
+    let writer_responses = responses.clone();
+    thread::spawn(move || write_requests(stdin, request_receiver, writer_responses));
+    let reader_responses = responses.clone();
+    thread::spawn(move || read_responses(stdout, reader_responses));
+
+    Ok(ExternalPlayer {
+      child,
+      requests,
+      responses,
+      next_request_id: 0,
+      timeout,
+    })
+  }
+}
+
+impl Player for ExternalPlayer {
+  fn request_action(&mut self, info: GameState) -> PendingAction {
+    self.next_request_id += 1;
+    let id = self.next_request_id;
+    let (sender, response) = PendingAction::channel();
+    {
+      let mut responses = self.responses.lock().unwrap();
+      if responses.has_exited {
+        return PendingAction::ready(BettingAction::Fold);
+      }
+      responses.request = Some((id, sender));
+    }
+
+    let request = serde_json::to_string(&ActionRequest { id, state: info }).unwrap();
+    if self.requests.send(request).is_err() {
+      return PendingAction::ready(BettingAction::Fold);
+    }
+
+    let timeout = self.timeout;
+    PendingAction::spawn(move || match response.wait_timeout(timeout) {
+      Ok(Some(action)) => action,
+      _ => BettingAction::Fold,
+    })
+  }
+}
+
+impl Drop for ExternalPlayer {
+  fn drop(&mut self) {
+    let _ = self.child.kill();
+    let _ = self.child.wait();
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use crate::game::{Game, Viewer};
+
+// The id is always the first field of a request, so the bots below can pick it out without parsing the JSON.
+const READ_ID: &str = r#"id=$(echo "$request" | sed 's/^{"id":\([0-9]*\).*/\1/')"#;
+
+fn get_state() -> GameState {
+  let mut game = Game::create(2, 1000);
+  while game.get_current_player_index().is_none() {
+    game.next();
+  }
+  game.get_view(Viewer::Player(game.get_current_player_index().unwrap()))
+}
+
+fn create_bot(script: &str, timeout: Duration) -> ExternalPlayer {
+  ExternalPlayer::create(Command::new("sh").args(["-c", script]), timeout).unwrap()
+}
+
+fn reply(action: &str) -> String {
+  format!(r#"{}; echo "{{\"id\":$id,\"action\":{}}}""#, READ_ID, action)
+}
+
+fn reply_to_every_request(action: &str) -> String {
+  format!("while read request; do {}; done", reply(action))
+}
+
+#[test]
+fn should_use_the_action_given_by_the_bot() {
+  let mut bot = create_bot(&reply_to_every_request(r#"{\"Raise\":40}"#), Duration::from_secs(5));
+  assert_eq!(Ok(BettingAction::Raise(40)), bot.request_action(get_state()).wait());
+  assert_eq!(Ok(BettingAction::Raise(40)), bot.request_action(get_state()).wait());
+}
+
+#[test]
+fn should_send_the_game_state_to_the_bot() {
+  let script = format!(
+    r#"read request; case "$request" in *current_player_index*) {};; esac"#,
+    reply(r#"\"AllIn\""#)
+  );
+  let mut bot = create_bot(&script, Duration::from_secs(5));
+  assert_eq!(Ok(BettingAction::AllIn), bot.request_action(get_state()).wait());
+}
+
+#[test]
+fn should_fold_when_the_bot_gives_an_invalid_action() {
+  let mut bot = create_bot(
+    "while read request; do echo 'raise please'; done",
+    Duration::from_secs(5),
+  );
+  assert_eq!(Ok(BettingAction::Fold), bot.request_action(get_state()).wait());
+}
+
+#[test]
+#[ntest::timeout(2000)]
+fn should_fold_when_the_bot_exits() {
+  let mut bot = create_bot("read request; exit 1", Duration::from_secs(10));
+  assert_eq!(Ok(BettingAction::Fold), bot.request_action(get_state()).wait());
+  assert_eq!(Ok(BettingAction::Fold), bot.request_action(get_state()).wait());
+}
+
+#[test]
+#[ntest::timeout(5000)]
+fn should_fold_when_the_bot_takes_too_long() {
+  let mut bot = create_bot("while read request; do sleep 10; done", Duration::from_millis(100));
+  assert_eq!(Ok(BettingAction::Fold), bot.request_action(get_state()).wait());
+}
+
+#[test]
+#[ntest::timeout(5000)]
+fn should_ignore_late_replies_to_requests_that_timed_out() {
+  let script = format!(
+    "read request; sleep 1.5; {}; {}",
+    reply(r#"\"AllIn\""#),
+    reply_to_every_request(r#"\"Call\""#)
+  );
+  let mut bot = create_bot(&script, Duration::from_secs(1));
+  assert_eq!(Ok(BettingAction::Fold), bot.request_action(get_state()).wait());
+  assert_eq!(Ok(BettingAction::Call), bot.request_action(get_state()).wait());
+  assert_eq!(Ok(BettingAction::Call), bot.request_action(get_state()).wait());
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_fold_when_the_bot_stops_reading() {
+  let mut bot = create_bot("sleep 10", Duration::from_millis(10));
+  // Enough requests to fill the pipe to the bot several times over.
+  for _ in 0..200 {
+    assert_eq!(Ok(BettingAction::Fold), bot.request_action(get_state()).wait());
+  }
+}
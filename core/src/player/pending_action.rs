@@ -0,0 +1,66 @@
+use crate::game::BettingAction;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+const DISCONNECTED_ERROR: &str = "The player stopped without giving an action.";
+
+pub struct ActionSender {
+  sender: Sender<BettingAction>,
+}
+
+impl ActionSender {
+  pub fn send(self, action: BettingAction) {
+    // The game may have already given up on this action, in which case there is nobody left to tell.
+    let _ = self.sender.send(action);
+  }
+}
+
+pub struct PendingAction {
+  receiver: Receiver<BettingAction>,
+}
+
+impl PendingAction {
+  pub fn channel() -> (ActionSender, PendingAction) {
+    let (sender, receiver) = channel();
+    (ActionSender { sender }, PendingAction { receiver })
+  }
+
+  pub fn ready(action: BettingAction) -> PendingAction {
+    let (sender, pending) = PendingAction::channel();
+    sender.send(action);
+    pending
+  }
+
+  pub fn spawn<F>(get_action: F) -> PendingAction
+  where
+    F: FnOnce() -> BettingAction + Send + 'static,
+  {
+    let (sender, pending) = PendingAction::channel();
+    thread::spawn(move || sender.send(get_action()));
+    pending
+  }
+
+  pub fn poll(&self) -> Result<Option<BettingAction>, &'static str> {
+    match self.receiver.try_recv() {
+      Ok(action) => Ok(Some(action)),
+      Err(TryRecvError::Empty) => Ok(None),
+      Err(TryRecvError::Disconnected) => Err(DISCONNECTED_ERROR),
+    }
+  }
+
+  pub fn wait_timeout(&self, timeout: Duration) -> Result<Option<BettingAction>, &'static str> {
+    match self.receiver.recv_timeout(timeout) {
+      Ok(action) => Ok(Some(action)),
+      Err(RecvTimeoutError::Timeout) => Ok(None),
+      Err(RecvTimeoutError::Disconnected) => Err(DISCONNECTED_ERROR),
+    }
+  }
+
+  pub fn wait(&self) -> Result<BettingAction, &'static str> {
+    self.receiver.recv().map_err(|_| DISCONNECTED_ERROR)
+  }
+}
+
+#[cfg(test)]
+mod tests;
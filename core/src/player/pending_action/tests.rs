@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn should_poll_a_ready_action_immediately() {
+  let pending = PendingAction::ready(BettingAction::Call);
+  assert_eq!(Ok(Some(BettingAction::Call)), pending.poll());
+}
+
+#[test]
+fn should_poll_nothing_until_the_action_is_sent() {
+  let (sender, pending) = PendingAction::channel();
+  assert_eq!(Ok(None), pending.poll());
+  sender.send(BettingAction::Raise(20));
+  assert_eq!(Ok(Some(BettingAction::Raise(20))), pending.poll());
+}
+
+#[test]
+fn should_error_when_the_sender_is_dropped_without_an_action() {
+  let (sender, pending) = PendingAction::channel();
+  drop(sender);
+  assert!(pending.poll().is_err());
+  assert!(pending.wait().is_err());
+}
+
+#[test]
+fn should_wait_for_a_spawned_action() {
+  let pending = PendingAction::spawn(|| {
+    thread::sleep(Duration::from_millis(20));
+    BettingAction::Fold
+  });
+  assert_eq!(Ok(BettingAction::Fold), pending.wait());
+}
+
+#[test]
+fn should_return_nothing_when_waiting_times_out() {
+  let (_sender, pending) = PendingAction::channel();
+  assert_eq!(Ok(None), pending.wait_timeout(Duration::from_millis(10)));
+}
@@ -1,5 +1,14 @@
-use crate::game::{BettingAction, GameState};
+use super::PendingAction;
+use crate::game::{BettingAction, GameState, ShowdownHand};
 
 pub trait Player {
-  fn request_action(&self, info: GameState) -> BettingAction;
+  fn request_action(&mut self, info: GameState) -> PendingAction;
+
+  fn on_hand_start(&mut self, _info: &GameState) {}
+
+  fn on_action_observed(&mut self, _player_index: u8, _action: BettingAction) {}
+
+  fn on_showdown(&mut self, _hands: &[ShowdownHand]) {}
+
+  fn on_hand_end(&mut self, _info: &GameState) {}
 }
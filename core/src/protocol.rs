@@ -0,0 +1,37 @@
+use crate::game::{BettingAction, GameState, ShowdownHand};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ClientMessage {
+  Join,
+  Action(BettingAction),
+  Leave,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ServerMessage {
+  Waiting { seated: u8, needed: u8 },
+  Seated { player_index: u8 },
+  State(GameState),
+  ActionObserved { player_index: u8, action: BettingAction },
+  Showdown(Vec<ShowdownHand>),
+  Left { cash_out: u32 },
+  Error(String),
+  GameOver,
+}
+
+// Sent to and received from external bots, where the id pairs each reply with the request it answers.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActionRequest {
+  pub id: u64,
+  pub state: GameState,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActionResponse {
+  pub id: u64,
+  pub action: BettingAction,
+}
+
+#[cfg(test)]
+mod tests;
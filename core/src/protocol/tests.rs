@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn client_messages_use_the_documented_wire_format() {
+  assert_eq!("\"Join\"", serde_json::to_string(&ClientMessage::Join).unwrap());
+  assert_eq!(
+    ClientMessage::Action(BettingAction::Raise(40)),
+    serde_json::from_str("{\"Action\":{\"Raise\":40}}").unwrap()
+  );
+}
+
+#[test]
+fn server_messages_use_the_documented_wire_format() {
+  let message = ServerMessage::ActionObserved {
+    player_index: 1,
+    action: BettingAction::Fold,
+  };
+  assert_eq!(
+    "{\"ActionObserved\":{\"player_index\":1,\"action\":\"Fold\"}}",
+    serde_json::to_string(&message).unwrap()
+  );
+  assert_eq!(
+    "{\"Error\":\"It is not your turn.\"}",
+    serde_json::to_string(&ServerMessage::Error("It is not your turn.".to_string())).unwrap()
+  );
+}
+
+#[test]
+fn bot_responses_use_the_documented_wire_format() {
+  assert_eq!(
+    ActionResponse {
+      id: 3,
+      action: BettingAction::Raise(40)
+    },
+    serde_json::from_str("{\"id\":3,\"action\":{\"Raise\":40}}").unwrap()
+  );
+}
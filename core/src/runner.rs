@@ -0,0 +1,147 @@
+use crate::game::{BettingAction, Game, GameState, Phase, PlayerState, ShowdownHand, Viewer};
+use crate::player::{PendingAction, Player};
+
+#[derive(Clone, Debug)]
+pub struct HandResult {
+  pub hand_number: u32,
+  pub showdown: Vec<ShowdownHand>,
+  pub players: Vec<PlayerState>,
+  pub rake: u32,
+}
+
+pub struct GameRunner {
+  game: Game,
+  players: Vec<Option<Box<dyn Player>>>,
+  pending_action: Option<PendingAction>,
+  hand_results: Vec<HandResult>,
+  is_finished: bool,
+}
+
+impl GameRunner {
+  pub fn create(game: Game, players: Vec<Box<dyn Player>>) -> GameRunner {
+    GameRunner {
+      game,
+      players: players.into_iter().map(Some).collect(),
+      pending_action: None,
+      hand_results: vec![],
+      is_finished: false,
+    }
+  }
+
+  pub fn get_game(&self) -> &Game {
+    &self.game
+  }
+
+  pub fn get_game_mut(&mut self) -> &mut Game {
+    &mut self.game
+  }
+
+  pub fn seat_player(&mut self, player: Box<dyn Player>, buy_in: u32) -> Result<u8, &'static str> {
+    let player_index = self.game.seat_player(buy_in)?;
+    self.players.push(Some(player));
+    Ok(player_index)
+  }
+
+  pub fn remove_player(&mut self, player_index: u8) -> Result<(Box<dyn Player>, u32), &'static str> {
+    let cash_out = self.game.leave(player_index)?;
+    let player = self.players[player_index as usize].take().unwrap();
+    Ok((player, cash_out))
+  }
+
+  pub fn get_hand_results(&self) -> &[HandResult] {
+    &self.hand_results
+  }
+
+  pub fn is_finished(&self) -> bool {
+    self.is_finished
+  }
+
+  pub fn poll(&mut self) -> Option<Phase> {
+    self.step(false)
+  }
+
+  pub fn run_hands(&mut self, num_hands: u32) -> Vec<HandResult> {
+    let num_results = self.hand_results.len();
+    while self.hand_results.len() < num_results + num_hands as usize && self.step(true).is_some() {}
+    self.hand_results[num_results..].to_vec()
+  }
+
+  pub fn run_to_completion(&mut self) -> Vec<HandResult> {
+    let num_results = self.hand_results.len();
+    while self.step(true).is_some() {}
+    self.hand_results[num_results..].to_vec()
+  }
+
+  fn step(&mut self, should_wait: bool) -> Option<Phase> {
+    if let Some(curr_index) = self.game.get_current_player_index() {
+      if let Some(action) = self.get_action(curr_index, should_wait) {
+        for player in self.players.iter_mut().flatten() {
+          player.on_action_observed(curr_index, action);
+        }
+      }
+    }
+
+    let prev_phase = self.game.get_state(None).phase;
+    let phase = self.game.next();
+    self.is_finished = phase.is_none();
+    self.notify_players(prev_phase, phase);
+    phase
+  }
+
+  fn get_action(&mut self, player_index: u8, should_wait: bool) -> Option<BettingAction> {
+    let game = &self.game;
+    let player = self.players[player_index as usize].as_mut().unwrap();
+    let pending = self
+      .pending_action
+      .get_or_insert_with(|| player.request_action(game.get_view(Viewer::Player(player_index))));
+
+    let result = match (should_wait, game.get_state(None).time_remaining) {
+      (false, _) => pending.poll(),
+      (true, Some(time_remaining)) => pending.wait_timeout(time_remaining.action_time + time_remaining.time_bank),
+      (true, None) => pending.wait().map(Some),
+    };
+
+    let action = match result {
+      Ok(Some(action)) => action,
+      Ok(None) => {
+        let action = self.game.action_current_player_if_timed_out();
+        if action.is_some() {
+          self.pending_action = None;
+        }
+        return action;
+      }
+      Err(_) => BettingAction::Fold,
+    };
+    self.pending_action = None;
+    self.game.action_current_player(action).unwrap();
+    Some(action)
+  }
+
+  fn notify_players(&mut self, prev_phase: Phase, phase: Option<Phase>) {
+    if prev_phase == Phase::Init && phase.is_some() {
+      for (idx, player) in self.players.iter_mut().enumerate() {
+        let Some(player) = player else { continue };
+        player.on_hand_start(&self.game.get_view(Viewer::Player(idx as u8)));
+      }
+    } else if prev_phase == Phase::Showdown {
+      let showdown = self.game.get_showdown().to_vec();
+      for (idx, player) in self.players.iter_mut().enumerate() {
+        let Some(player) = player else { continue };
+        if !showdown.is_empty() {
+          player.on_showdown(&showdown);
+        }
+        player.on_hand_end(&self.game.get_view(Viewer::Player(idx as u8)));
+      }
+      let final_state: GameState = self.game.get_state(None);
+      self.hand_results.push(HandResult {
+        hand_number: self.hand_results.len() as u32 + 1,
+        showdown,
+        players: final_state.players,
+        rake: self.game.get_hand_rake(),
+      });
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests;
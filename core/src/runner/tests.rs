@@ -0,0 +1,191 @@
+use super::*;
+use crate::game::{ManualClock, Rake, TimeLimit};
+use crate::player::{ActionSender, CallingPlayer};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+struct AllInPlayer;
+
+impl Player for AllInPlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    PendingAction::ready(BettingAction::AllIn)
+  }
+}
+
+#[derive(Default)]
+struct Events {
+  hands_started: u32,
+  actions_observed: u32,
+  showdowns: u32,
+  hands_ended: u32,
+}
+
+struct RecordingPlayer {
+  events: Rc<RefCell<Events>>,
+}
+
+impl Player for RecordingPlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    PendingAction::ready(BettingAction::Call)
+  }
+
+  fn on_hand_start(&mut self, _info: &GameState) {
+    self.events.borrow_mut().hands_started += 1;
+  }
+
+  fn on_action_observed(&mut self, _player_index: u8, _action: BettingAction) {
+    self.events.borrow_mut().actions_observed += 1;
+  }
+
+  fn on_showdown(&mut self, _hands: &[ShowdownHand]) {
+    self.events.borrow_mut().showdowns += 1;
+  }
+
+  fn on_hand_end(&mut self, _info: &GameState) {
+    self.events.borrow_mut().hands_ended += 1;
+  }
+}
+
+struct SilentPlayer {
+  senders: Vec<ActionSender>,
+}
+
+impl Player for SilentPlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    let (sender, pending) = PendingAction::channel();
+    self.senders.push(sender);
+    pending
+  }
+}
+
+#[test]
+fn should_run_the_given_number_of_hands() {
+  let players: Vec<Box<dyn Player>> = vec![
+    Box::new(CallingPlayer { id: 1 }),
+    Box::new(CallingPlayer { id: 2 }),
+    Box::new(CallingPlayer { id: 3 }),
+  ];
+  let mut runner = GameRunner::create(Game::create(3, 1000), players);
+
+  let results = runner.run_hands(3);
+  assert_eq!(vec![1, 2, 3], results.iter().map(|r| r.hand_number).collect::<Vec<_>>());
+  for result in results {
+    assert_eq!(3000, result.players.iter().map(|p| p.wallet).sum::<u32>());
+  }
+  assert_eq!(3, runner.get_hand_results().len());
+}
+
+#[test]
+#[ntest::timeout(5000)]
+fn should_run_until_one_player_has_all_the_money() {
+  let players: Vec<Box<dyn Player>> = vec![Box::new(AllInPlayer), Box::new(AllInPlayer)];
+  let mut runner = GameRunner::create(Game::create(2, 1000), players);
+
+  let results = runner.run_to_completion();
+  assert!(runner.is_finished());
+  let last_result = results.last().unwrap();
+  assert!(last_result.players.iter().any(|p| p.wallet == 2000));
+  assert_eq!(None, runner.poll());
+}
+
+#[test]
+fn should_notify_players_of_the_hand_lifecycle() {
+  let events = Rc::new(RefCell::new(Events::default()));
+  let players: Vec<Box<dyn Player>> = vec![
+    Box::new(RecordingPlayer { events: events.clone() }),
+    Box::new(CallingPlayer { id: 2 }),
+  ];
+  let mut runner = GameRunner::create(Game::create(2, 1000), players);
+
+  runner.run_hands(2);
+  let events = events.borrow();
+  assert_eq!(2, events.hands_started);
+  assert_eq!(2, events.showdowns);
+  assert_eq!(2, events.hands_ended);
+  assert_eq!(2 * 8, events.actions_observed);
+}
+
+#[test]
+fn should_not_block_when_polling_a_player_yet_to_act() {
+  let players: Vec<Box<dyn Player>> = vec![
+    Box::new(SilentPlayer { senders: vec![] }),
+    Box::new(SilentPlayer { senders: vec![] }),
+  ];
+  let mut game = Game::create(2, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::from_secs(60),
+    time_bank: Duration::ZERO,
+  });
+  let mut runner = GameRunner::create(game, players);
+
+  assert_eq!(Some(Phase::PreFlop), runner.poll());
+  let current_player = runner.get_game().get_current_player_index();
+  assert!(current_player.is_some());
+  assert_eq!(Some(Phase::PreFlop), runner.poll());
+  assert_eq!(current_player, runner.get_game().get_current_player_index());
+}
+
+#[test]
+fn should_fold_for_a_player_who_times_out() {
+  let players: Vec<Box<dyn Player>> = vec![
+    Box::new(SilentPlayer { senders: vec![] }),
+    Box::new(SilentPlayer { senders: vec![] }),
+  ];
+  let mut game = Game::create(2, 1000);
+  game.set_time_limit(TimeLimit {
+    action_time: Duration::from_secs(10),
+    time_bank: Duration::ZERO,
+  });
+  let clock = Arc::new(ManualClock::default());
+  game.set_clock(clock.clone());
+  let mut runner = GameRunner::create(game, players);
+
+  runner.poll();
+  let player_index = runner.get_game().get_current_player_index().unwrap();
+  runner.poll();
+  assert_eq!(Some(player_index), runner.get_game().get_current_player_index());
+
+  clock.advance(Duration::from_secs(10));
+  runner.poll();
+  assert!(runner.get_game().get_state(None).players[player_index as usize].is_folded);
+}
+
+#[test]
+fn should_deal_in_players_who_join_between_hands() {
+  let events = Rc::new(RefCell::new(Events::default()));
+  let players: Vec<Box<dyn Player>> = vec![Box::new(CallingPlayer { id: 1 }), Box::new(CallingPlayer { id: 2 })];
+  let mut runner = GameRunner::create(Game::create(2, 1000), players);
+  runner.run_hands(1);
+
+  let player = Box::new(RecordingPlayer { events: events.clone() });
+  assert_eq!(Ok(2), runner.seat_player(player, 1000));
+  runner.get_game_mut().sit_out(0).unwrap();
+  let results = runner.run_hands(1);
+
+  assert_eq!(1, events.borrow().hands_started);
+  assert!(results[0].players[0].is_sitting_out);
+  assert_eq!(3000, results[0].players.iter().map(|p| p.wallet).sum::<u32>());
+}
+
+#[test]
+fn should_report_the_rake_for_each_hand() {
+  let players: Vec<Box<dyn Player>> = vec![Box::new(CallingPlayer { id: 1 }), Box::new(CallingPlayer { id: 2 })];
+  let mut game = Game::create(2, 1000);
+  game
+    .set_rake(Some(Rake {
+      percentage: 10.0,
+      cap: 100,
+      no_flop_no_drop: false,
+    }))
+    .unwrap();
+  let mut runner = GameRunner::create(game, players);
+
+  let results = runner.run_hands(2);
+  for result in &results {
+    assert_eq!(4, result.rake);
+  }
+  assert_eq!(2000 - 8, results[1].players.iter().map(|p| p.wallet).sum::<u32>());
+  assert_eq!(8, runner.get_game().get_total_rake());
+}
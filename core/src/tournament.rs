@@ -0,0 +1,196 @@
+use crate::game::Game;
+use crate::player::Player;
+use crate::runner::{GameRunner, HandResult};
+use std::cmp::Reverse;
+
+mod multi_table;
+
+pub use multi_table::MultiTableTournament;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlindLevel {
+  pub blind: u32,
+  pub num_hands: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TournamentConfig {
+  pub buy_in: u32,
+  pub starting_stack: u32,
+  pub payout_percentages: Vec<u32>,
+  pub blind_levels: Vec<BlindLevel>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Standing {
+  pub position: u8,
+  pub player_index: u8,
+  pub chips: u32,
+  pub payout: u32,
+}
+
+fn validate_config(num_players: usize, config: &TournamentConfig) -> Result<(), &'static str> {
+  if num_players < 2 {
+    return Err("A tournament needs at least two players.");
+  }
+  if num_players > u8::MAX as usize {
+    return Err("A tournament can have at most 255 players.");
+  }
+  if config.blind_levels.is_empty() {
+    return Err("A tournament needs at least one blind level.");
+  }
+  if config.payout_percentages.iter().sum::<u32>() != 100 {
+    return Err("Payouts must add up to 100 percent.");
+  }
+  if config.payout_percentages.len() > num_players {
+    return Err("There are more payouts than players.");
+  }
+  Ok(())
+}
+
+fn get_blind_for_hand(config: &TournamentConfig, hands_played: u32) -> u32 {
+  let mut remaining_hands = hands_played;
+  for level in &config.blind_levels {
+    if remaining_hands < level.num_hands {
+      return level.blind;
+    }
+    remaining_hands -= level.num_hands;
+  }
+  config.blind_levels.last().unwrap().blind
+}
+
+fn get_payout(config: &TournamentConfig, prize_pool: u32, position: u8) -> u32 {
+  let payout = |pct: &u32| prize_pool * pct / 100;
+  match config.payout_percentages.get(position as usize - 1) {
+    Some(pct) if position == 1 => {
+      let remainder = prize_pool - config.payout_percentages.iter().map(payout).sum::<u32>();
+      payout(pct) + remainder
+    }
+    Some(pct) => payout(pct),
+    None => 0,
+  }
+}
+
+// Players who cannot cover the next big blind are knocked out. When several go out together, whoever has more chips
+// left, and then whoever started the hand with more, finishes higher.
+fn get_knocked_out_players(chips: &[u32], starting_chips: &[u32], blind: u32, finishing_order: &[u8]) -> Vec<u8> {
+  let mut knocked_out = (0..chips.len() as u8)
+    .filter(|&idx| chips[idx as usize] < blind && !finishing_order.contains(&idx))
+    .collect::<Vec<_>>();
+  knocked_out.sort_by_key(|&idx| (chips[idx as usize], starting_chips[idx as usize], Reverse(idx)));
+  knocked_out
+}
+
+fn get_standings(config: &TournamentConfig, prize_pool: u32, chips: &[u32], finishing_order: &[u8]) -> Vec<Standing> {
+  let mut remaining = (0..chips.len() as u8)
+    .filter(|idx| !finishing_order.contains(idx))
+    .collect::<Vec<_>>();
+  remaining.sort_by_key(|&idx| (Reverse(chips[idx as usize]), idx));
+
+  remaining
+    .into_iter()
+    .chain(finishing_order.iter().rev().copied())
+    .enumerate()
+    .map(|(idx, player_index)| Standing {
+      position: idx as u8 + 1,
+      player_index,
+      chips: chips[player_index as usize],
+      payout: get_payout(config, prize_pool, idx as u8 + 1),
+    })
+    .collect()
+}
+
+pub struct Tournament {
+  runner: GameRunner,
+  config: TournamentConfig,
+  num_players: u8,
+  hands_played: u32,
+  finishing_order: Vec<u8>,
+}
+
+impl Tournament {
+  pub fn create(players: Vec<Box<dyn Player>>, config: TournamentConfig) -> Result<Tournament, &'static str> {
+    validate_config(players.len(), &config)?;
+
+    let num_players = players.len() as u8;
+    let mut game = Game::create(num_players, config.starting_stack);
+    game.set_blind(config.blind_levels[0].blind)?;
+    Ok(Tournament {
+      runner: GameRunner::create(game, players),
+      config,
+      num_players,
+      hands_played: 0,
+      finishing_order: vec![],
+    })
+  }
+
+  pub fn get_runner(&self) -> &GameRunner {
+    &self.runner
+  }
+
+  pub fn get_prize_pool(&self) -> u32 {
+    self.config.buy_in * self.num_players as u32
+  }
+
+  pub fn is_finished(&self) -> bool {
+    self.runner.is_finished()
+  }
+
+  pub fn run_hand(&mut self) -> Option<HandResult> {
+    let starting_chips = self.get_chips();
+    let result = self.runner.run_hands(1).pop()?;
+    self.hands_played += 1;
+
+    if !self.runner.is_finished() {
+      let blind = get_blind_for_hand(&self.config, self.hands_played);
+      self.runner.get_game_mut().set_blind(blind).unwrap();
+    }
+
+    let blind = self.runner.get_game().get_blind();
+    let chips = result.players.iter().map(|p| p.wallet).collect::<Vec<_>>();
+    let knocked_out = get_knocked_out_players(&chips, &starting_chips, blind, &self.finishing_order);
+    self.finishing_order.extend(knocked_out);
+
+    if self.runner.is_finished() {
+      let mut remaining = result
+        .players
+        .iter()
+        .filter(|p| !self.finishing_order.contains(&p.player_index))
+        .map(|p| (p.wallet, Reverse(p.player_index)))
+        .collect::<Vec<_>>();
+      remaining.sort();
+      self
+        .finishing_order
+        .extend(remaining.into_iter().map(|(_, Reverse(idx))| idx));
+    }
+    Some(result)
+  }
+
+  pub fn run_to_completion(&mut self) -> Vec<Standing> {
+    while self.run_hand().is_some() {}
+    self.get_standings()
+  }
+
+  fn get_chips(&self) -> Vec<u32> {
+    self
+      .runner
+      .get_game()
+      .get_state(None)
+      .players
+      .iter()
+      .map(|p| p.wallet)
+      .collect()
+  }
+
+  pub fn get_standings(&self) -> Vec<Standing> {
+    get_standings(
+      &self.config,
+      self.get_prize_pool(),
+      &self.get_chips(),
+      &self.finishing_order,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests;
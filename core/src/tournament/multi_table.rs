@@ -0,0 +1,231 @@
+use super::{get_blind_for_hand, get_knocked_out_players, get_standings, validate_config, Standing, TournamentConfig};
+use crate::game::Game;
+use crate::player::Player;
+use crate::runner::GameRunner;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+struct Table {
+  runner: GameRunner,
+  player_ids: Vec<u8>,
+}
+
+impl Table {
+  fn get_seated_player_indexes(&self) -> Vec<u8> {
+    let game = self.runner.get_game();
+    game
+      .get_state(None)
+      .players
+      .iter()
+      .filter(|p| !p.has_left && !p.is_eliminated && p.wallet >= game.get_blind())
+      .map(|p| p.player_index)
+      .collect()
+  }
+}
+
+pub struct MultiTableTournament {
+  tables: Vec<Table>,
+  config: TournamentConfig,
+  max_seats: u8,
+  num_players: u8,
+  rounds_played: u32,
+  finishing_order: Vec<u8>,
+  rng: StdRng,
+}
+
+impl MultiTableTournament {
+  pub fn create(
+    players: Vec<Box<dyn Player>>,
+    config: TournamentConfig,
+    max_seats: u8,
+    seed: u64,
+  ) -> Result<MultiTableTournament, &'static str> {
+    validate_config(players.len(), &config)?;
+    if max_seats < 2 {
+      return Err("Tables need at least two seats.");
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let num_players = players.len() as u8;
+    let num_tables = (num_players as usize).div_ceil(max_seats as usize);
+
+    let mut seat_draw = (0..num_players).collect::<Vec<_>>();
+    seat_draw.shuffle(&mut rng);
+    let mut players = players.into_iter().map(Some).collect::<Vec<_>>();
+
+    let tables = (0..num_tables)
+      .map(|table_idx| {
+        let player_ids = seat_draw
+          .iter()
+          .skip(table_idx)
+          .step_by(num_tables)
+          .copied()
+          .collect::<Vec<_>>();
+        let mut game = Game::create(player_ids.len() as u8, config.starting_stack);
+        game.set_seed(rng.gen());
+        game.set_blind(config.blind_levels[0].blind).unwrap();
+        let table_players = player_ids
+          .iter()
+          .map(|&id| players[id as usize].take().unwrap())
+          .collect();
+        Table {
+          runner: GameRunner::create(game, table_players),
+          player_ids,
+        }
+      })
+      .collect();
+
+    Ok(MultiTableTournament {
+      tables,
+      config,
+      max_seats,
+      num_players,
+      rounds_played: 0,
+      finishing_order: vec![],
+      rng,
+    })
+  }
+
+  pub fn get_prize_pool(&self) -> u32 {
+    self.config.buy_in * self.num_players as u32
+  }
+
+  pub fn get_tables(&self) -> Vec<&GameRunner> {
+    self.tables.iter().map(|t| &t.runner).collect()
+  }
+
+  pub fn get_table_sizes(&self) -> Vec<usize> {
+    self
+      .tables
+      .iter()
+      .map(|t| t.get_seated_player_indexes().len())
+      .collect()
+  }
+
+  pub fn get_table_player_ids(&self, table_index: usize) -> Vec<u8> {
+    let table = &self.tables[table_index];
+    table
+      .get_seated_player_indexes()
+      .iter()
+      .map(|&idx| table.player_ids[idx as usize])
+      .collect()
+  }
+
+  pub fn is_finished(&self) -> bool {
+    self.get_table_sizes().iter().sum::<usize>() < 2
+  }
+
+  fn get_chips(&self) -> Vec<u32> {
+    let mut chips = vec![0; self.num_players as usize];
+    for table in &self.tables {
+      for player in table.runner.get_game().get_state(None).players {
+        if !player.has_left {
+          chips[table.player_ids[player.player_index as usize] as usize] = player.wallet;
+        }
+      }
+    }
+    chips
+  }
+
+  pub fn run_round(&mut self) -> bool {
+    if self.is_finished() {
+      return false;
+    }
+
+    let starting_chips = self.get_chips();
+    for table in self.tables.iter_mut() {
+      if table.get_seated_player_indexes().len() > 1 {
+        table.runner.run_hands(1);
+      }
+    }
+    self.rounds_played += 1;
+
+    let blind = get_blind_for_hand(&self.config, self.rounds_played);
+    for table in self.tables.iter_mut() {
+      table.runner.get_game_mut().set_blind(blind).unwrap();
+    }
+
+    let chips = self.get_chips();
+    let knocked_out = get_knocked_out_players(&chips, &starting_chips, blind, &self.finishing_order);
+    self.finishing_order.extend(knocked_out);
+
+    let remaining = (0..self.num_players)
+      .filter(|idx| !self.finishing_order.contains(idx))
+      .collect::<Vec<_>>();
+    if remaining.len() == 1 {
+      self.finishing_order.extend(remaining);
+    } else {
+      self.balance_tables();
+    }
+    true
+  }
+
+  pub fn run_to_completion(&mut self) -> Vec<Standing> {
+    while self.run_round() {}
+    self.get_standings()
+  }
+
+  fn pick_table(&mut self, pick_largest: bool) -> usize {
+    let sizes = self.get_table_sizes();
+    let target_size = if pick_largest {
+      sizes.iter().max()
+    } else {
+      sizes.iter().min()
+    };
+    let candidates = (0..sizes.len())
+      .filter(|&idx| Some(&sizes[idx]) == target_size)
+      .collect::<Vec<_>>();
+    *candidates.choose(&mut self.rng).unwrap()
+  }
+
+  fn move_player(&mut self, from: &mut Table, player_index: u8, to_index: usize) {
+    let (player, chips) = from.runner.remove_player(player_index).unwrap();
+    let player_id = from.player_ids[player_index as usize];
+    let to = &mut self.tables[to_index];
+    to.runner.seat_player(player, chips).unwrap();
+    to.player_ids.push(player_id);
+  }
+
+  // Tables are broken, smallest first, until no more tables are needed than the remaining players can fill. Players
+  // are then moved from the largest tables to the smallest until every table is within one player of each other.
+  fn balance_tables(&mut self) {
+    loop {
+      let sizes = self.get_table_sizes();
+      let num_remaining = sizes.iter().sum::<usize>();
+      let tables_needed = num_remaining.div_ceil(self.max_seats as usize);
+
+      if self.tables.len() > tables_needed {
+        let table_index = self.pick_table(false);
+        let mut broken_table = self.tables.remove(table_index);
+        for player_index in broken_table.get_seated_player_indexes() {
+          let to_index = self.pick_table(false);
+          self.move_player(&mut broken_table, player_index, to_index);
+        }
+        continue;
+      }
+
+      if sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1 {
+        break;
+      }
+      let from_index = self.pick_table(true);
+      let to_index = self.pick_table(false);
+      let mut from = self.tables.remove(from_index);
+      let player_index = *from.get_seated_player_indexes().choose(&mut self.rng).unwrap();
+      let to_index = if to_index > from_index { to_index - 1 } else { to_index };
+      self.move_player(&mut from, player_index, to_index);
+      self.tables.insert(from_index, from);
+    }
+  }
+
+  pub fn get_standings(&self) -> Vec<Standing> {
+    get_standings(
+      &self.config,
+      self.get_prize_pool(),
+      &self.get_chips(),
+      &self.finishing_order,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use super::*;
+use crate::game::{BettingAction, GameState};
+use crate::player::PendingAction;
+use crate::tournament::BlindLevel;
+
+struct AllInPlayer;
+
+impl Player for AllInPlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    PendingAction::ready(BettingAction::AllIn)
+  }
+}
+
+fn create_config() -> TournamentConfig {
+  TournamentConfig {
+    buy_in: 10,
+    starting_stack: 1000,
+    payout_percentages: vec![50, 30, 20],
+    blind_levels: vec![
+      BlindLevel {
+        blind: 20,
+        num_hands: 5,
+      },
+      BlindLevel {
+        blind: 100,
+        num_hands: 5,
+      },
+    ],
+  }
+}
+
+fn create_tournament(num_players: u8, max_seats: u8, seed: u64) -> MultiTableTournament {
+  let players = (0..num_players)
+    .map(|_| Box::new(AllInPlayer) as Box<dyn Player>)
+    .collect();
+  MultiTableTournament::create(players, create_config(), max_seats, seed).unwrap()
+}
+
+#[test]
+fn should_seat_players_evenly_across_tables() {
+  let tournament = create_tournament(20, 9, 1);
+  assert_eq!(vec![7, 7, 6], tournament.get_table_sizes());
+
+  let mut player_ids = (0..3)
+    .flat_map(|idx| tournament.get_table_player_ids(idx))
+    .collect::<Vec<_>>();
+  player_ids.sort();
+  assert_eq!((0..20).collect::<Vec<_>>(), player_ids);
+}
+
+#[test]
+fn should_not_allow_tables_with_less_than_two_seats() {
+  let players = (0..4).map(|_| Box::new(AllInPlayer) as Box<dyn Player>).collect();
+  assert!(MultiTableTournament::create(players, create_config(), 1, 1).is_err());
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_balance_and_break_tables_down_to_a_final_table() {
+  let mut tournament = create_tournament(20, 6, 2);
+  let mut had_final_table = false;
+  while tournament.run_round() {
+    let sizes = tournament.get_table_sizes();
+    let num_remaining = sizes.iter().sum::<usize>();
+    if num_remaining < 2 {
+      break;
+    }
+    assert_eq!(num_remaining.div_ceil(6), sizes.len());
+    assert!(sizes.iter().max().unwrap() - sizes.iter().min().unwrap() <= 1);
+    had_final_table |= sizes.len() == 1;
+  }
+  assert!(had_final_table);
+  assert!(tournament.is_finished());
+
+  let standings = tournament.get_standings();
+  assert_eq!(20, standings.len());
+  assert_eq!(20_000, standings.iter().map(|s| s.chips).sum::<u32>());
+  assert_eq!(200, standings.iter().map(|s| s.payout).sum::<u32>());
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_play_out_the_same_tournament_for_the_same_seed() {
+  let standings = create_tournament(15, 6, 3).run_to_completion();
+  assert_eq!(standings, create_tournament(15, 6, 3).run_to_completion());
+}
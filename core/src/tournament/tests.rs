@@ -0,0 +1,119 @@
+use super::*;
+use crate::game::{BettingAction, GameState};
+use crate::player::{CallingPlayer, PendingAction};
+
+struct AllInPlayer;
+
+impl Player for AllInPlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    PendingAction::ready(BettingAction::AllIn)
+  }
+}
+
+fn create_config(payout_percentages: Vec<u32>) -> TournamentConfig {
+  TournamentConfig {
+    buy_in: 100,
+    starting_stack: 1000,
+    payout_percentages,
+    blind_levels: vec![
+      BlindLevel {
+        blind: 20,
+        num_hands: 2,
+      },
+      BlindLevel {
+        blind: 50,
+        num_hands: 1,
+      },
+      BlindLevel {
+        blind: 100,
+        num_hands: 1,
+      },
+    ],
+  }
+}
+
+fn create_players(num_players: u8) -> Vec<Box<dyn Player>> {
+  (0..num_players)
+    .map(|_| Box::new(AllInPlayer) as Box<dyn Player>)
+    .collect()
+}
+
+#[test]
+fn should_validate_the_config() {
+  assert!(Tournament::create(create_players(3), create_config(vec![60, 30])).is_err());
+  assert!(Tournament::create(create_players(2), create_config(vec![50, 30, 20])).is_err());
+  assert!(Tournament::create(create_players(1), create_config(vec![100])).is_err());
+  let mut config = create_config(vec![100]);
+  config.blind_levels = vec![];
+  assert!(Tournament::create(create_players(2), config).is_err());
+}
+
+#[test]
+fn should_raise_the_blinds_by_level() {
+  let players: Vec<Box<dyn Player>> = vec![Box::new(CallingPlayer { id: 1 }), Box::new(CallingPlayer { id: 2 })];
+  let mut tournament = Tournament::create(players, create_config(vec![100])).unwrap();
+  let mut blinds = vec![tournament.get_runner().get_game().get_blind()];
+  for _ in 0..4 {
+    tournament.run_hand();
+    blinds.push(tournament.get_runner().get_game().get_blind());
+  }
+  assert_eq!(vec![20, 20, 50, 100, 100], blinds);
+}
+
+#[test]
+#[ntest::timeout(5000)]
+fn should_pay_out_the_prize_pool_by_finishing_position() {
+  let mut tournament = Tournament::create(create_players(4), create_config(vec![50, 30, 20])).unwrap();
+  let standings = tournament.run_to_completion();
+  assert!(tournament.is_finished());
+
+  assert_eq!(
+    vec![1, 2, 3, 4],
+    standings.iter().map(|s| s.position).collect::<Vec<_>>()
+  );
+  assert_eq!(
+    vec![200, 120, 80, 0],
+    standings.iter().map(|s| s.payout).collect::<Vec<_>>()
+  );
+  let mut player_indexes = standings.iter().map(|s| s.player_index).collect::<Vec<_>>();
+  player_indexes.sort();
+  assert_eq!(vec![0, 1, 2, 3], player_indexes);
+  assert!(standings[0].chips >= 2000);
+}
+
+#[test]
+fn should_give_rounding_remainders_to_the_winner() {
+  let tournament = Tournament::create(create_players(3), create_config(vec![67, 33])).unwrap();
+  assert_eq!(300, tournament.get_prize_pool());
+  let standings = tournament.get_standings();
+  assert_eq!(vec![201, 99, 0], standings.iter().map(|s| s.payout).collect::<Vec<_>>());
+}
+
+#[test]
+fn should_rank_players_knocked_out_together_by_their_chips() {
+  let mut config = create_config(vec![100]);
+  config.blind_levels = vec![
+    BlindLevel {
+      blind: 20,
+      num_hands: 1,
+    },
+    BlindLevel {
+      blind: 1_000_000,
+      num_hands: 1,
+    },
+  ];
+  let players: Vec<Box<dyn Player>> = (1..=3)
+    .map(|id| Box::new(CallingPlayer { id }) as Box<dyn Player>)
+    .collect();
+  let mut tournament = Tournament::create(players, config).unwrap();
+  assert!(tournament.run_hand().is_some());
+  assert!(tournament.run_hand().is_none());
+  assert!(tournament.is_finished());
+
+  let standings = tournament.get_standings();
+  assert_eq!(3000, standings.iter().map(|s| s.chips).sum::<u32>());
+  for pair in standings.windows(2) {
+    assert!(pair[0].chips >= pair[1].chips);
+  }
+  assert_eq!(300, standings[0].payout);
+}
@@ -0,0 +1,25 @@
+mod holdem;
+mod omaha;
+mod short_deck;
+mod stud;
+mod types;
+
+pub use holdem::Holdem;
+pub use omaha::{Omaha, OmahaHiLo};
+pub use short_deck::ShortDeck;
+pub use stud::SevenCardStud;
+pub use types::{ForcedBets, Street, Variant};
+
+use std::sync::Arc;
+
+pub fn get_variant_by_name(name: &str) -> Option<Arc<dyn Variant>> {
+  let variant: Arc<dyn Variant> = match name {
+    "holdem" => Arc::new(Holdem),
+    "omaha" => Arc::new(Omaha),
+    "omaha_hi_lo" => Arc::new(OmahaHiLo),
+    "short_deck" => Arc::new(ShortDeck),
+    "seven_card_stud" => Arc::new(SevenCardStud),
+    _ => return None,
+  };
+  Some(variant)
+}
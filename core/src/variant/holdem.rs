@@ -0,0 +1,58 @@
+use super::{Street, Variant};
+use crate::deck::Deck;
+use crate::evaluator::{chance_to_win, chance_to_win_preflop, get_hand_score};
+use crate::game::Phase;
+
+pub(super) fn community_card_streets(hole_cards: u8) -> Vec<Street> {
+  vec![
+    Street {
+      phase: Phase::PreFlop,
+      hole_cards,
+      up_cards: 0,
+      table_cards: 0,
+    },
+    Street {
+      phase: Phase::Flop,
+      hole_cards: 0,
+      up_cards: 0,
+      table_cards: 3,
+    },
+    Street {
+      phase: Phase::Turn,
+      hole_cards: 0,
+      up_cards: 0,
+      table_cards: 1,
+    },
+    Street {
+      phase: Phase::River,
+      hole_cards: 0,
+      up_cards: 0,
+      table_cards: 1,
+    },
+  ]
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Holdem;
+
+impl Variant for Holdem {
+  fn get_name(&self) -> &'static str {
+    "holdem"
+  }
+
+  fn get_streets(&self) -> Vec<Street> {
+    community_card_streets(2)
+  }
+
+  fn get_hand_score(&self, table: &Deck, hand: &Deck) -> u16 {
+    get_hand_score(table, hand)
+  }
+
+  fn chance_to_win(&self, table: &Deck, hand: &Deck) -> f32 {
+    chance_to_win(table, hand)
+  }
+
+  fn chance_to_win_preflop(&self, hand: &Deck, num_players: u8) -> f32 {
+    chance_to_win_preflop(hand, num_players)
+  }
+}
@@ -0,0 +1,69 @@
+use super::holdem::community_card_streets;
+use super::{Street, Variant};
+use crate::deck::Deck;
+use crate::evaluator::{chance_to_win_omaha, get_omaha_hand_score, get_omaha_low_hand_score, OmahaHand};
+
+fn to_omaha_hand(hand: &Deck) -> Option<OmahaHand> {
+  OmahaHand::try_from(hand).ok()
+}
+
+// Hands that can not be scored yet, such as between hands, score 0 rather than panicking.
+fn get_score(table: &Deck, hand: &Deck) -> u16 {
+  match to_omaha_hand(hand) {
+    Some(hand) if (3..=5).contains(&table.get_cards().len()) => get_omaha_hand_score(table, &hand),
+    _ => 0,
+  }
+}
+
+fn get_chance_to_win(table: &Deck, hand: &Deck) -> f32 {
+  to_omaha_hand(hand).map_or(0.0, |hand| chance_to_win_omaha(table, &hand))
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Omaha;
+
+impl Variant for Omaha {
+  fn get_name(&self) -> &'static str {
+    "omaha"
+  }
+
+  fn get_streets(&self) -> Vec<Street> {
+    community_card_streets(4)
+  }
+
+  fn get_hand_score(&self, table: &Deck, hand: &Deck) -> u16 {
+    get_score(table, hand)
+  }
+
+  fn chance_to_win(&self, table: &Deck, hand: &Deck) -> f32 {
+    get_chance_to_win(table, hand)
+  }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct OmahaHiLo;
+
+impl Variant for OmahaHiLo {
+  fn get_name(&self) -> &'static str {
+    "omaha_hi_lo"
+  }
+
+  fn get_streets(&self) -> Vec<Street> {
+    community_card_streets(4)
+  }
+
+  fn get_hand_score(&self, table: &Deck, hand: &Deck) -> u16 {
+    get_score(table, hand)
+  }
+
+  fn get_low_hand_score(&self, table: &Deck, hand: &Deck) -> Option<u16> {
+    to_omaha_hand(hand).and_then(|hand| get_omaha_low_hand_score(table, &hand))
+  }
+
+  fn chance_to_win(&self, table: &Deck, hand: &Deck) -> f32 {
+    get_chance_to_win(table, hand)
+  }
+}
+
+#[cfg(test)]
+mod tests;
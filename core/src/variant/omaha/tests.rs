@@ -0,0 +1,47 @@
+use super::*;
+use crate::card::{Card, Rank, Suit};
+
+fn get_table() -> Deck {
+  Deck::from_cards(&vec![
+    Card::new(Suit::Heart, Rank::Two),
+    Card::new(Suit::Heart, Rank::Three),
+    Card::new(Suit::Club, Rank::Four),
+  ])
+}
+
+fn get_hand() -> Deck {
+  Deck::from_cards(&vec![
+    Card::new(Suit::Spade, Rank::Ace),
+    Card::new(Suit::Spade, Rank::Five),
+    Card::new(Suit::Diamond, Rank::King),
+    Card::new(Suit::Diamond, Rank::Queen),
+  ])
+}
+
+#[test]
+fn should_score_a_four_card_hand() {
+  assert!(Omaha.get_hand_score(&get_table(), &get_hand()) > 0);
+  assert!(OmahaHiLo.get_low_hand_score(&get_table(), &get_hand()).is_some());
+}
+
+#[test]
+fn should_score_hands_that_are_not_four_cards_as_nothing() {
+  let empty_hand = Deck::new();
+  assert_eq!(0, Omaha.get_hand_score(&get_table(), &empty_hand));
+  assert_eq!(0, OmahaHiLo.get_hand_score(&get_table(), &empty_hand));
+  assert_eq!(None, OmahaHiLo.get_low_hand_score(&get_table(), &empty_hand));
+  assert_eq!(0.0, Omaha.chance_to_win(&get_table(), &empty_hand));
+}
+
+#[test]
+fn should_score_hands_without_a_flop_as_nothing() {
+  assert_eq!(0, Omaha.get_hand_score(&Deck::new(), &get_hand()));
+  assert_eq!(None, OmahaHiLo.get_low_hand_score(&Deck::new(), &get_hand()));
+}
+
+#[test]
+fn should_give_a_lower_preflop_chance_against_more_players() {
+  let heads_up = Omaha.chance_to_win_preflop(&get_hand(), 2);
+  assert!(heads_up > 0.0 && heads_up < 100.0);
+  assert!(Omaha.chance_to_win_preflop(&get_hand(), 6) < heads_up);
+}
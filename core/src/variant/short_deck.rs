@@ -0,0 +1,29 @@
+use super::holdem::community_card_streets;
+use super::{Street, Variant};
+use crate::deck::Deck;
+use crate::evaluator::{chance_to_win_short_deck, get_short_deck_hand_score};
+
+#[derive(Debug, Copy, Clone)]
+pub struct ShortDeck;
+
+impl Variant for ShortDeck {
+  fn get_name(&self) -> &'static str {
+    "short_deck"
+  }
+
+  fn get_deck(&self) -> Deck {
+    Deck::short_deck()
+  }
+
+  fn get_streets(&self) -> Vec<Street> {
+    community_card_streets(2)
+  }
+
+  fn get_hand_score(&self, table: &Deck, hand: &Deck) -> u16 {
+    get_short_deck_hand_score(table, hand)
+  }
+
+  fn chance_to_win(&self, table: &Deck, hand: &Deck) -> f32 {
+    chance_to_win_short_deck(table, hand)
+  }
+}
@@ -0,0 +1,124 @@
+use super::{ForcedBets, Street, Variant};
+use crate::card::*;
+use crate::deck::Deck;
+use crate::evaluator::{chance_to_win_stud, get_hand_score};
+use crate::game::Phase;
+
+// Bring-in ties are broken by suit, from clubs (lowest) to spades (highest).
+fn get_bring_in_value(card: &Card) -> u8 {
+  let suit_value = match card.suit {
+    Suit::Club => 0,
+    Suit::Diamond => 1,
+    Suit::Heart => 2,
+    Suit::Spade => 3,
+  };
+  card.rank as u8 * 4 + suit_value
+}
+
+// Only pairs, trips and quads count towards visible hands, followed by the ranks of the remaining cards.
+fn get_visible_hand_value(cards: &Deck) -> u32 {
+  let mut rank_counts = [0u8; 13];
+  for c in cards.get_cards() {
+    rank_counts[c.rank as usize] += 1;
+  }
+  let mut groups = rank_counts
+    .iter()
+    .enumerate()
+    .filter(|(_, &count)| count > 0)
+    .map(|(rank, &count)| (count, rank as u32))
+    .collect::<Vec<_>>();
+  groups.sort_by(|a, b| b.cmp(a));
+
+  let num_pairs = groups.iter().filter(|(count, _)| *count == 2).count() as u32;
+  let category = match groups.first().map(|(count, _)| *count).unwrap_or(0) {
+    4 => 4,
+    3 => 3,
+    2 if num_pairs > 1 => 2,
+    2 => 1,
+    _ => 0,
+  };
+
+  groups
+    .iter()
+    .take(4)
+    .fold(category, |value, (_, rank)| value << 4 | (rank + 1))
+    << (4 * (4 - groups.len().min(4)))
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SevenCardStud;
+
+impl Variant for SevenCardStud {
+  fn get_name(&self) -> &'static str {
+    "seven_card_stud"
+  }
+
+  fn get_streets(&self) -> Vec<Street> {
+    vec![
+      Street {
+        phase: Phase::ThirdStreet,
+        hole_cards: 2,
+        up_cards: 1,
+        table_cards: 0,
+      },
+      Street {
+        phase: Phase::FourthStreet,
+        hole_cards: 0,
+        up_cards: 1,
+        table_cards: 0,
+      },
+      Street {
+        phase: Phase::FifthStreet,
+        hole_cards: 0,
+        up_cards: 1,
+        table_cards: 0,
+      },
+      Street {
+        phase: Phase::SixthStreet,
+        hole_cards: 0,
+        up_cards: 1,
+        table_cards: 0,
+      },
+      Street {
+        phase: Phase::SeventhStreet,
+        hole_cards: 1,
+        up_cards: 0,
+        table_cards: 0,
+      },
+    ]
+  }
+
+  fn get_forced_bets(&self) -> ForcedBets {
+    ForcedBets::AntesAndBringIn
+  }
+
+  fn get_first_to_act(&self, phase: Phase, visible_cards: &[Option<Deck>]) -> Option<usize> {
+    let players = visible_cards
+      .iter()
+      .enumerate()
+      .filter_map(|(idx, cards)| cards.map(|c| (idx, c)));
+    if phase == Phase::ThirdStreet {
+      players
+        .filter_map(|(idx, cards)| cards.get_cards().first().map(|c| (idx, get_bring_in_value(c))))
+        .min_by_key(|(_, value)| *value)
+        .map(|(idx, _)| idx)
+    } else {
+      players
+        .map(|(idx, cards)| (idx, get_visible_hand_value(&cards)))
+        .rev()
+        .max_by_key(|(_, value)| *value)
+        .map(|(idx, _)| idx)
+    }
+  }
+
+  fn get_hand_score(&self, table: &Deck, hand: &Deck) -> u16 {
+    get_hand_score(table, hand)
+  }
+
+  fn chance_to_win(&self, table: &Deck, hand: &Deck) -> f32 {
+    chance_to_win_stud(table, hand)
+  }
+}
+
+#[cfg(test)]
+mod tests;
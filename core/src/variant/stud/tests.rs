@@ -0,0 +1,92 @@
+use super::*;
+
+fn visible(cards: Vec<Card>) -> Option<Deck> {
+  Some(Deck::from_cards(&cards))
+}
+
+#[test]
+fn should_pick_the_lowest_up_card_for_the_bring_in() {
+  let visible_cards = vec![
+    visible(vec![Card::new(Suit::Heart, Rank::King)]),
+    visible(vec![Card::new(Suit::Spade, Rank::Three)]),
+    visible(vec![Card::new(Suit::Club, Rank::Nine)]),
+  ];
+  assert_eq!(
+    Some(1),
+    SevenCardStud.get_first_to_act(Phase::ThirdStreet, &visible_cards)
+  );
+}
+
+#[test]
+fn should_break_bring_in_ties_by_suit() {
+  let visible_cards = vec![
+    visible(vec![Card::new(Suit::Spade, Rank::Two)]),
+    visible(vec![Card::new(Suit::Diamond, Rank::Two)]),
+    visible(vec![Card::new(Suit::Club, Rank::Two)]),
+  ];
+  assert_eq!(
+    Some(2),
+    SevenCardStud.get_first_to_act(Phase::ThirdStreet, &visible_cards)
+  );
+}
+
+#[test]
+fn should_pick_the_best_visible_hand_to_act_first() {
+  let visible_cards = vec![
+    visible(vec![
+      Card::new(Suit::Heart, Rank::Ace),
+      Card::new(Suit::Heart, Rank::King),
+    ]),
+    visible(vec![
+      Card::new(Suit::Spade, Rank::Three),
+      Card::new(Suit::Club, Rank::Three),
+    ]),
+    visible(vec![
+      Card::new(Suit::Club, Rank::Ace),
+      Card::new(Suit::Club, Rank::Queen),
+    ]),
+  ];
+  assert_eq!(
+    Some(1),
+    SevenCardStud.get_first_to_act(Phase::FourthStreet, &visible_cards)
+  );
+}
+
+#[test]
+fn should_compare_visible_high_cards_in_order() {
+  let visible_cards = vec![
+    visible(vec![
+      Card::new(Suit::Heart, Rank::Ace),
+      Card::new(Suit::Heart, Rank::Nine),
+      Card::new(Suit::Club, Rank::Two),
+    ]),
+    visible(vec![
+      Card::new(Suit::Spade, Rank::Ace),
+      Card::new(Suit::Club, Rank::Jack),
+      Card::new(Suit::Diamond, Rank::Four),
+    ]),
+  ];
+  assert_eq!(
+    Some(1),
+    SevenCardStud.get_first_to_act(Phase::FifthStreet, &visible_cards)
+  );
+}
+
+#[test]
+fn should_skip_folded_players_when_picking_who_acts_first() {
+  let visible_cards = vec![
+    None,
+    visible(vec![
+      Card::new(Suit::Spade, Rank::Three),
+      Card::new(Suit::Club, Rank::Four),
+    ]),
+    visible(vec![
+      Card::new(Suit::Club, Rank::Five),
+      Card::new(Suit::Club, Rank::Six),
+    ]),
+  ];
+  assert_eq!(
+    Some(2),
+    SevenCardStud.get_first_to_act(Phase::FourthStreet, &visible_cards)
+  );
+}
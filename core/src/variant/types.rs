@@ -0,0 +1,52 @@
+use crate::deck::Deck;
+use crate::game::Phase;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Street {
+  pub phase: Phase,
+  pub hole_cards: u8,
+  pub up_cards: u8,
+  pub table_cards: u8,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ForcedBets {
+  Blinds,
+  AntesAndBringIn,
+}
+
+pub trait Variant: std::fmt::Debug + Send + Sync {
+  fn get_name(&self) -> &'static str;
+
+  fn get_deck(&self) -> Deck {
+    Deck::full_deck()
+  }
+
+  fn get_streets(&self) -> Vec<Street>;
+
+  fn get_num_hole_cards(&self) -> u8 {
+    self.get_streets().iter().map(|s| s.hole_cards + s.up_cards).sum()
+  }
+
+  fn get_forced_bets(&self) -> ForcedBets {
+    ForcedBets::Blinds
+  }
+
+  fn get_first_to_act(&self, _phase: Phase, _visible_cards: &[Option<Deck>]) -> Option<usize> {
+    None
+  }
+
+  fn get_hand_score(&self, table: &Deck, hand: &Deck) -> u16;
+
+  fn get_low_hand_score(&self, _table: &Deck, _hand: &Deck) -> Option<u16> {
+    None
+  }
+
+  fn chance_to_win(&self, table: &Deck, hand: &Deck) -> f32;
+
+  // A percentage like the hold'em preflop tables. Other variants treat each opponent as an independent heads-up match.
+  fn chance_to_win_preflop(&self, hand: &Deck, num_players: u8) -> f32 {
+    let heads_up = self.chance_to_win(&Deck::new(), hand);
+    heads_up.powi(num_players.saturating_sub(1).max(1) as i32) * 100.0
+  }
+}
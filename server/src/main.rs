@@ -0,0 +1,25 @@
+mod remote_player;
+mod server;
+
+use server::{Server, ServerConfig};
+use std::env;
+
+fn get_arg<T: std::str::FromStr>(args: &[String], index: usize, default: T) -> T {
+  args
+    .get(index)
+    .map_or(default, |arg| arg.parse().ok().expect("Arguments must be numbers."))
+}
+
+fn main() {
+  let args = env::args().collect::<Vec<_>>();
+  let port: u16 = get_arg(&args, 1, 7878);
+  let config = ServerConfig {
+    num_players: get_arg(&args, 2, 2),
+    starting_stack: get_arg(&args, 3, 1000),
+    time_limit: None,
+  };
+
+  let server = Server::bind(("0.0.0.0", port), config).expect("Unable to listen on that port.");
+  println!("Listening on {}", server.get_address().unwrap());
+  server.run();
+}
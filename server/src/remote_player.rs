@@ -0,0 +1,78 @@
+use crate::server::send_message;
+use rusty_poker_core::game::{BettingAction, GameState, ShowdownHand};
+use rusty_poker_core::player::{ActionSender, PendingAction, Player};
+use rusty_poker_core::protocol::ServerMessage;
+use std::cell::RefCell;
+use std::net::TcpStream;
+use std::rc::Rc;
+
+#[derive(Default)]
+pub struct RemoteSeat {
+  player_index: Option<u8>,
+  action_sender: Option<ActionSender>,
+  queued_action: Option<BettingAction>,
+  has_left: bool,
+}
+
+impl RemoteSeat {
+  pub fn set_player_index(&mut self, player_index: u8) {
+    self.player_index = Some(player_index);
+  }
+
+  // Actions can arrive before the game asks for them, so they are held until it does.
+  pub fn send_action(&mut self, action: BettingAction) {
+    match self.action_sender.take() {
+      Some(sender) => sender.send(action),
+      None => self.queued_action = Some(action),
+    }
+  }
+
+  pub fn leave(&mut self) {
+    self.has_left = true;
+    self.action_sender = None;
+    self.queued_action = None;
+  }
+}
+
+pub struct RemotePlayer {
+  seat: Rc<RefCell<RemoteSeat>>,
+  writer: TcpStream,
+}
+
+impl RemotePlayer {
+  pub fn create(seat: Rc<RefCell<RemoteSeat>>, writer: TcpStream) -> RemotePlayer {
+    RemotePlayer { seat, writer }
+  }
+}
+
+impl Player for RemotePlayer {
+  fn request_action(&mut self, _info: GameState) -> PendingAction {
+    let mut seat = self.seat.borrow_mut();
+    if seat.has_left {
+      return PendingAction::ready(BettingAction::Fold);
+    }
+    if let Some(action) = seat.queued_action.take() {
+      return PendingAction::ready(action);
+    }
+    let (sender, pending) = PendingAction::channel();
+    seat.action_sender = Some(sender);
+    pending
+  }
+
+  fn on_action_observed(&mut self, player_index: u8, action: BettingAction) {
+    let mut seat = self.seat.borrow_mut();
+    if seat.player_index == Some(player_index) {
+      seat.action_sender = None;
+      seat.queued_action = None;
+    }
+    drop(seat);
+    send_message(
+      &mut self.writer,
+      &ServerMessage::ActionObserved { player_index, action },
+    );
+  }
+
+  fn on_showdown(&mut self, hands: &[ShowdownHand]) {
+    send_message(&mut self.writer, &ServerMessage::Showdown(hands.to_vec()));
+  }
+}
@@ -0,0 +1,336 @@
+use crate::remote_player::{RemotePlayer, RemoteSeat};
+use rusty_poker_core::game::{Game, Phase, TimeLimit, Viewer};
+use rusty_poker_core::player::Player;
+use rusty_poker_core::protocol::{ClientMessage, ServerMessage};
+use rusty_poker_core::runner::GameRunner;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::rc::Rc;
+use std::sync::mpsc::{channel, Sender};
+use std::thread;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+pub struct ServerConfig {
+  pub num_players: u8,
+  pub starting_stack: u32,
+  pub time_limit: Option<TimeLimit>,
+}
+
+enum ClientEvent {
+  Message(usize, ClientMessage),
+  Invalid(usize, String),
+  Disconnected(usize),
+}
+
+// Enough to tell when a player's view has changed without comparing the whole state.
+type StateKey = (usize, usize, Phase, Option<u8>, usize);
+
+struct Client {
+  writer: TcpStream,
+  player_index: Option<u8>,
+  seat: Option<Rc<RefCell<RemoteSeat>>>,
+  last_state_key: Option<StateKey>,
+}
+
+pub struct Server {
+  listener: TcpListener,
+  config: ServerConfig,
+}
+
+struct Table {
+  config: ServerConfig,
+  clients: HashMap<usize, Client>,
+  waiting_clients: Vec<usize>,
+  leaving_players: Vec<(Option<usize>, u8)>,
+  runner: Option<GameRunner>,
+}
+
+// Clients whose writes fail or time out are shut down, which their reader then reports as a disconnect. This stops a
+// client that is not reading from holding up the whole table.
+pub fn send_message(writer: &mut TcpStream, message: &ServerMessage) -> bool {
+  let is_sent = writeln!(writer, "{}", serde_json::to_string(message).unwrap()).is_ok();
+  if !is_sent {
+    let _ = writer.shutdown(Shutdown::Both);
+  }
+  is_sent
+}
+
+fn read_messages(client_id: usize, stream: TcpStream, events: Sender<ClientEvent>) {
+  for line in BufReader::new(stream).lines() {
+    let Ok(line) = line else { break };
+    if line.trim().is_empty() {
+      continue;
+    }
+    let event = match serde_json::from_str(&line) {
+      Ok(message) => ClientEvent::Message(client_id, message),
+      Err(err) => ClientEvent::Invalid(client_id, err.to_string()),
+    };
+    if events.send(event).is_err() {
+      return;
+    }
+  }
+  let _ = events.send(ClientEvent::Disconnected(client_id));
+}
+
+impl Server {
+  pub fn bind(address: impl ToSocketAddrs, config: ServerConfig) -> io::Result<Server> {
+    let listener = TcpListener::bind(address)?;
+    listener.set_nonblocking(true)?;
+    Ok(Server { listener, config })
+  }
+
+  pub fn get_address(&self) -> io::Result<SocketAddr> {
+    self.listener.local_addr()
+  }
+
+  pub fn run(self) {
+    let (event_sender, events) = channel();
+    let mut table = Table {
+      config: self.config,
+      clients: HashMap::new(),
+      waiting_clients: vec![],
+      leaving_players: vec![],
+      runner: None,
+    };
+    let mut next_client_id = 0;
+
+    loop {
+      while let Ok((stream, _)) = self.listener.accept() {
+        let client_id = next_client_id;
+        next_client_id += 1;
+        let reader = stream
+          .set_nonblocking(false)
+          .and_then(|_| stream.set_write_timeout(Some(WRITE_TIMEOUT)))
+          .and_then(|_| stream.try_clone());
+        if let Ok(reader) = reader {
+          let event_sender = event_sender.clone();
+          thread::spawn(move || read_messages(client_id, reader, event_sender));
+          table.add_client(client_id, stream);
+        }
+      }
+
+      if let Ok(event) = events.recv_timeout(POLL_INTERVAL) {
+        table.handle_event(event);
+      }
+      while let Ok(event) = events.try_recv() {
+        table.handle_event(event);
+      }
+
+      if !table.step() {
+        return;
+      }
+    }
+  }
+}
+
+impl Table {
+  fn add_client(&mut self, client_id: usize, writer: TcpStream) {
+    self.clients.insert(
+      client_id,
+      Client {
+        writer,
+        player_index: None,
+        seat: None,
+        last_state_key: None,
+      },
+    );
+  }
+
+  fn send_to(&mut self, client_id: usize, message: &ServerMessage) {
+    if let Some(client) = self.clients.get_mut(&client_id) {
+      send_message(&mut client.writer, message);
+    }
+  }
+
+  fn send_error(&mut self, client_id: usize, error: &str) {
+    self.send_to(client_id, &ServerMessage::Error(error.to_string()));
+  }
+
+  fn handle_event(&mut self, event: ClientEvent) {
+    match event {
+      ClientEvent::Message(client_id, message) => self.handle_message(client_id, message),
+      ClientEvent::Invalid(client_id, error) => self.send_error(client_id, &format!("Invalid message: {}", error)),
+      ClientEvent::Disconnected(client_id) => {
+        self.waiting_clients.retain(|&id| id != client_id);
+        if let Some(client) = self.clients.remove(&client_id) {
+          self.leave(None, client);
+        }
+      }
+    }
+  }
+
+  fn handle_message(&mut self, client_id: usize, message: ClientMessage) {
+    let Some(client) = self.clients.get(&client_id) else {
+      return;
+    };
+    match message {
+      ClientMessage::Join => {
+        if client.player_index.is_some() || self.waiting_clients.contains(&client_id) {
+          return self.send_error(client_id, "You have already joined.");
+        }
+        self.waiting_clients.push(client_id);
+        if self.runner.is_none() {
+          let waiting = ServerMessage::Waiting {
+            seated: self.waiting_clients.len() as u8,
+            needed: self.config.num_players,
+          };
+          for id in self.waiting_clients.clone() {
+            self.send_to(id, &waiting);
+          }
+        }
+      }
+      ClientMessage::Action(action) => {
+        let current_player_index = self
+          .runner
+          .as_ref()
+          .and_then(|r| r.get_game().get_current_player_index());
+        match (&client.seat, client.player_index) {
+          (Some(seat), Some(player_index)) if current_player_index == Some(player_index) => {
+            seat.borrow_mut().send_action(action);
+          }
+          (Some(_), _) => self.send_error(client_id, "It is not your turn."),
+          _ => self.send_error(client_id, "You are not seated."),
+        }
+      }
+      ClientMessage::Leave => {
+        if self.waiting_clients.contains(&client_id) {
+          self.waiting_clients.retain(|&id| id != client_id);
+          return self.send_to(client_id, &ServerMessage::Left { cash_out: 0 });
+        }
+        if client.player_index.is_none() {
+          return self.send_error(client_id, "You are not seated.");
+        }
+        let client = self.clients.get_mut(&client_id).unwrap();
+        let player_index = client.player_index.take().unwrap();
+        client.seat.take().unwrap().borrow_mut().leave();
+        self.leaving_players.push((Some(client_id), player_index));
+      }
+    }
+  }
+
+  fn leave(&mut self, client_id: Option<usize>, client: Client) {
+    if let (Some(seat), Some(player_index)) = (client.seat, client.player_index) {
+      seat.borrow_mut().leave();
+      self.leaving_players.push((client_id, player_index));
+    }
+  }
+
+  fn create_player(&mut self, client_id: usize) -> Box<dyn Player> {
+    let client = self.clients.get_mut(&client_id).unwrap();
+    let seat = Rc::new(RefCell::new(RemoteSeat::default()));
+    client.seat = Some(seat.clone());
+    Box::new(RemotePlayer::create(seat, client.writer.try_clone().unwrap()))
+  }
+
+  fn seat_client(&mut self, client_id: usize, player_index: u8) {
+    let client = self.clients.get_mut(&client_id).unwrap();
+    client.player_index = Some(player_index);
+    client
+      .seat
+      .as_ref()
+      .unwrap()
+      .borrow_mut()
+      .set_player_index(player_index);
+    send_message(&mut client.writer, &ServerMessage::Seated { player_index });
+  }
+
+  fn start_game(&mut self) {
+    let client_ids = self.waiting_clients.drain(..).collect::<Vec<_>>();
+    let mut game = Game::create(client_ids.len() as u8, self.config.starting_stack);
+    if let Some(time_limit) = self.config.time_limit {
+      game.set_time_limit(time_limit);
+    }
+    let players = client_ids
+      .iter()
+      .map(|&client_id| self.create_player(client_id))
+      .collect();
+    self.runner = Some(GameRunner::create(game, players));
+    for (player_index, client_id) in client_ids.into_iter().enumerate() {
+      self.seat_client(client_id, player_index as u8);
+    }
+  }
+
+  // Players can only join or leave the game between hands.
+  fn update_seats(&mut self) {
+    let Some(runner) = self.runner.as_mut() else {
+      if self.waiting_clients.len() >= self.config.num_players as usize {
+        self.start_game();
+      }
+      return;
+    };
+    if runner.get_game().get_state(None).phase != Phase::Init {
+      return;
+    }
+
+    for (client_id, player_index) in std::mem::take(&mut self.leaving_players) {
+      let runner = self.runner.as_mut().unwrap();
+      if let (Ok((_, cash_out)), Some(client_id)) = (runner.remove_player(player_index), client_id) {
+        self.send_to(client_id, &ServerMessage::Left { cash_out });
+      }
+    }
+
+    for client_id in std::mem::take(&mut self.waiting_clients) {
+      if !self.clients.contains_key(&client_id) {
+        continue;
+      }
+      let player = self.create_player(client_id);
+      let runner = self.runner.as_mut().unwrap();
+      match runner.seat_player(player, self.config.starting_stack) {
+        Ok(player_index) => self.seat_client(client_id, player_index),
+        Err(err) => {
+          self.clients.get_mut(&client_id).unwrap().seat = None;
+          self.send_error(client_id, err);
+        }
+      }
+    }
+  }
+
+  fn send_states(&mut self) {
+    let Some(runner) = self.runner.as_ref() else {
+      return;
+    };
+    let game = runner.get_game();
+    for client in self.clients.values_mut() {
+      let Some(player_index) = client.player_index else {
+        continue;
+      };
+      let state = game.get_view(Viewer::Player(player_index));
+      let state_key = (
+        runner.get_hand_results().len(),
+        game.get_hand_actions().len(),
+        state.phase,
+        state.current_player_index,
+        state.players.len(),
+      );
+      if client.last_state_key != Some(state_key) {
+        client.last_state_key = Some(state_key);
+        send_message(&mut client.writer, &ServerMessage::State(state));
+      }
+    }
+  }
+
+  fn step(&mut self) -> bool {
+    self.update_seats();
+    let Some(runner) = self.runner.as_mut() else {
+      return true;
+    };
+    if runner.poll().is_none() {
+      self.send_states();
+      for client in self.clients.values_mut() {
+        send_message(&mut client.writer, &ServerMessage::GameOver);
+      }
+      return false;
+    }
+    self.send_states();
+    // Players who are leaving are still cashed out at the end of the hand, even if nobody else is left.
+    self.clients.values().any(|c| c.player_index.is_some()) || !self.leaving_players.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests;
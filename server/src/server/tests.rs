@@ -0,0 +1,272 @@
+use super::*;
+use rusty_poker_core::game::{BettingAction, GameState};
+use std::net::SocketAddr;
+use std::thread::JoinHandle;
+
+struct TestClient {
+  reader: BufReader<TcpStream>,
+  writer: TcpStream,
+}
+
+impl TestClient {
+  fn connect(address: SocketAddr) -> TestClient {
+    let stream = TcpStream::connect(address).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    TestClient {
+      reader: BufReader::new(stream.try_clone().unwrap()),
+      writer: stream,
+    }
+  }
+
+  fn send_line(&mut self, line: &str) {
+    writeln!(self.writer, "{}", line).unwrap();
+  }
+
+  fn send(&mut self, message: &ClientMessage) {
+    self.send_line(&serde_json::to_string(message).unwrap());
+  }
+
+  fn receive(&mut self) -> ServerMessage {
+    let mut line = String::new();
+    self.reader.read_line(&mut line).unwrap();
+    serde_json::from_str(&line).unwrap()
+  }
+
+  fn receive_until<T>(&mut self, mut matches: impl FnMut(ServerMessage) -> Option<T>) -> T {
+    loop {
+      if let Some(value) = matches(self.receive()) {
+        return value;
+      }
+    }
+  }
+
+  fn receive_state(&mut self) -> GameState {
+    self.receive_until(|m| match m {
+      ServerMessage::State(state) => Some(state),
+      _ => None,
+    })
+  }
+
+  fn join(&mut self) -> u8 {
+    self.send(&ClientMessage::Join);
+    self.receive_until(|m| match m {
+      ServerMessage::Seated { player_index } => Some(player_index),
+      _ => None,
+    })
+  }
+}
+
+fn start_server(num_players: u8) -> (SocketAddr, JoinHandle<()>) {
+  let config = ServerConfig {
+    num_players,
+    starting_stack: 1000,
+    time_limit: None,
+  };
+  let server = Server::bind("127.0.0.1:0", config).unwrap();
+  let address = server.get_address().unwrap();
+  (address, thread::spawn(move || server.run()))
+}
+
+fn play_until_game_over(client: &mut TestClient, player_index: u8, action: BettingAction) {
+  loop {
+    match client.receive() {
+      ServerMessage::State(state) if state.current_player_index == Some(player_index) => {
+        client.send(&ClientMessage::Action(action));
+      }
+      ServerMessage::GameOver => return,
+      _ => {}
+    }
+  }
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_wait_for_enough_players_before_seating_them() {
+  let (address, _) = start_server(2);
+  let mut first = TestClient::connect(address);
+  first.send(&ClientMessage::Join);
+  assert!(matches!(
+    first.receive(),
+    ServerMessage::Waiting { seated: 1, needed: 2 }
+  ));
+
+  let mut second = TestClient::connect(address);
+  assert_eq!(1, second.join());
+  assert_eq!(
+    0,
+    first.receive_until(|m| match m {
+      ServerMessage::Seated { player_index } => Some(player_index),
+      _ => None,
+    })
+  );
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_only_send_players_their_own_hole_cards() {
+  let (address, _) = start_server(2);
+  let mut clients = [TestClient::connect(address), TestClient::connect(address)];
+  clients[0].send(&ClientMessage::Join);
+  clients[1].send(&ClientMessage::Join);
+
+  for client in clients.iter_mut() {
+    let player_index = client.receive_until(|m| match m {
+      ServerMessage::Seated { player_index } => Some(player_index),
+      _ => None,
+    });
+    let state = client.receive_state();
+    assert_eq!(2, state.hand.get_cards().len());
+    assert_eq!(None, state.remaining_deck);
+    for player in state.players {
+      assert_eq!(player.player_index == player_index, player.hole_cards.is_some());
+    }
+  }
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_reject_actions_out_of_turn_and_invalid_messages() {
+  let (address, _) = start_server(2);
+  let mut first = TestClient::connect(address);
+  first.send(&ClientMessage::Action(BettingAction::Call));
+  assert!(matches!(first.receive(), ServerMessage::Error(e) if e == "You are not seated."));
+
+  first.send_line("{\"Raise\":");
+  assert!(matches!(first.receive(), ServerMessage::Error(e) if e.starts_with("Invalid message")));
+
+  let mut second = TestClient::connect(address);
+  first.send(&ClientMessage::Join);
+  assert!(matches!(first.receive(), ServerMessage::Waiting { .. }));
+  second.send(&ClientMessage::Join);
+  let current_player_index = first.receive_until(|m| match m {
+    ServerMessage::State(state) => state.current_player_index,
+    _ => None,
+  });
+  let mut waiting_client = if current_player_index == 0 { second } else { first };
+  waiting_client.send(&ClientMessage::Action(BettingAction::Call));
+  let error = waiting_client.receive_until(|m| match m {
+    ServerMessage::Error(e) => Some(e),
+    _ => None,
+  });
+  assert_eq!("It is not your turn.", error);
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_play_until_one_player_has_all_the_chips() {
+  let (address, server) = start_server(2);
+  let handles = (0..2)
+    .map(|_| {
+      thread::spawn(move || {
+        let mut client = TestClient::connect(address);
+        let player_index = client.join();
+        play_until_game_over(&mut client, player_index, BettingAction::AllIn);
+      })
+    })
+    .collect::<Vec<_>>();
+  for handle in handles {
+    handle.join().unwrap();
+  }
+  server.join().unwrap();
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_fold_for_and_remove_players_who_disconnect() {
+  let (address, server) = start_server(2);
+  let mut staying = TestClient::connect(address);
+  let mut leaving = TestClient::connect(address);
+  staying.send(&ClientMessage::Join);
+  let player_index = leaving.join();
+  drop(leaving);
+
+  let staying_index = 1 - player_index;
+  let mut observed_fold = false;
+  loop {
+    match staying.receive() {
+      ServerMessage::State(state) if state.current_player_index == Some(staying_index) => {
+        staying.send(&ClientMessage::Action(BettingAction::Call));
+      }
+      ServerMessage::ActionObserved {
+        player_index: idx,
+        action,
+      } if idx == player_index => {
+        observed_fold |= action == BettingAction::Fold;
+      }
+      ServerMessage::GameOver => break,
+      _ => {}
+    }
+  }
+  assert!(observed_fold);
+  server.join().unwrap();
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_cash_out_players_who_leave() {
+  let (address, server) = start_server(2);
+  let mut leaving = TestClient::connect(address);
+  leaving.send(&ClientMessage::Join);
+  let staying = thread::spawn(move || {
+    let mut client = TestClient::connect(address);
+    let player_index = client.join();
+    play_until_game_over(&mut client, player_index, BettingAction::Call);
+  });
+  leaving.receive_state();
+
+  leaving.send(&ClientMessage::Leave);
+  let cash_out = leaving.receive_until(|m| match m {
+    ServerMessage::Left { cash_out } => Some(cash_out),
+    _ => None,
+  });
+  assert!(cash_out < 1000);
+  staying.join().unwrap();
+  server.join().unwrap();
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_cash_out_every_player_when_they_all_leave() {
+  let (address, server) = start_server(2);
+  let mut clients = [TestClient::connect(address), TestClient::connect(address)];
+  clients[0].send(&ClientMessage::Join);
+  clients[1].send(&ClientMessage::Join);
+  for client in clients.iter_mut() {
+    client.receive_state();
+    client.send(&ClientMessage::Leave);
+  }
+  for client in clients.iter_mut() {
+    client.receive_until(|m| match m {
+      ServerMessage::Left { .. } => Some(()),
+      _ => None,
+    });
+  }
+  server.join().unwrap();
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_stop_when_every_player_disconnects() {
+  let (address, server) = start_server(2);
+  let mut clients = [TestClient::connect(address), TestClient::connect(address)];
+  clients[0].send(&ClientMessage::Join);
+  clients[1].send(&ClientMessage::Join);
+  for client in clients.iter_mut() {
+    client.receive_state();
+  }
+  drop(clients);
+  server.join().unwrap();
+}
+
+#[test]
+#[ntest::timeout(10000)]
+fn should_give_up_on_clients_that_stop_reading() {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let _client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+  let (mut stream, _) = listener.accept().unwrap();
+  stream.set_write_timeout(Some(WRITE_TIMEOUT)).unwrap();
+
+  let message = ServerMessage::Error("x".repeat(64 * 1024));
+  while send_message(&mut stream, &message) {}
+  assert!(!send_message(&mut stream, &ServerMessage::GameOver));
+}